@@ -1,7 +1,12 @@
-use ark_snark::SNARK;
-use ark_groth16::{Groth16, ProvingKey, VerifyingKey, Proof};
-use ark_groth16::r1cs_to_qap::LibsnarkReduction;
-use ark_bn254::{Bn254, Fr};
+use ark_bls12_381::Bls12_381;
+use ark_bn254::Bn254;
+use ark_ec::pairing::Pairing;
+use ark_groth16::Groth16;
+use arkworks_regex_circuit::{
+    prover::{self, encode_input, public_inputs, Curve},
+    regex::create_regex_and_dfa_from_str_and_defs,
+    structs::SubstringDefinitionsJson,
+};
 use rand::rngs::OsRng;
 use std::io::{self, Write};
 
@@ -9,42 +14,73 @@ mod generated_circuit;
 use crate::generated_circuit::RegexCircuit;
 
 const MAX_LEN: usize = 256;
+// generated_circuit.rs를 생성할 때 사용한 정규식
+const REGEX: &str = r"^[a-zA-Z]+[0-9]*@gmail\.com$";
 
 fn main() {
+    // 커브는 첫 번째 인자로 선택 (기본값: bn254)
+    let curve = match std::env::args().nth(1).map(|name| name.parse::<Curve>()) {
+        Some(Ok(curve)) => curve,
+        Some(Err(e)) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+        None => Curve::Bn254,
+    };
+    println!("[+] Curve: {}", curve);
+
     // 입력을 stdin으로 받아 처리
     let input_string = read_input();
 
-    // 입력된 문자열을 u64로 변환해 벡터에 넣음 (각 문자를 u64로 변환)
-    let input: Vec<Fr> = input_string
-        .chars()
-        .map(|c| Fr::from(c as u64))  // 각 문자를 u64로 변환
-        .collect();
+    // 증명 전에 입력이 정규식과 일치하는지 확인
+    let dfa = create_regex_and_dfa_from_str_and_defs(
+        REGEX,
+        SubstringDefinitionsJson { transitions: vec![] },
+    )
+    .expect("Failed to compile regex")
+    .dfa;
+    if let Err(e) = dfa.check(input_string.as_bytes()) {
+        println!("{}", e);
+        return;
+    }
+    if input_string.len() > MAX_LEN {
+        println!("Input is longer than {} bytes", MAX_LEN);
+        return;
+    }
+
+    let is_valid = match curve {
+        Curve::Bn254 => prove_and_verify::<Bn254>(&input_string),
+        Curve::Bls12_381 => prove_and_verify::<Bls12_381>(&input_string),
+    };
+
+    println!("Verification result: {}", is_valid);
+}
+
+/// Runs setup, prove and verify for the generated circuit over the pairing `E`.
+fn prove_and_verify<E: Pairing>(input_string: &str) -> bool {
+    // 입력된 문자열의 각 바이트를 필드 원소로 변환
+    let input = encode_input::<E::ScalarField>(input_string);
 
     // Define the circuit with the correct maximum length
-    let circuit = RegexCircuit { input: input.clone(), max_len: MAX_LEN };
+    let circuit = RegexCircuit { input, max_len: MAX_LEN };
     println!("[+] Circuit done");
 
     // Prove and verify the circuit
     let mut rng = OsRng;
 
     // Setup proving and verifying keys
-    let (pk, vk): (ProvingKey<Bn254>, VerifyingKey<Bn254>) = Groth16::<Bn254, LibsnarkReduction>::circuit_specific_setup(
-        circuit.clone(), &mut rng
-    ).unwrap();
+    let (pk, vk) = prover::setup::<_, Groth16<E>, _, _>(circuit.clone(), &mut rng).unwrap();
     println!("[+] Proving and Verifying keys have been generated");
 
     // Prove for the input circuit
-    let proof: Proof<Bn254> = Groth16::<Bn254, LibsnarkReduction>::prove(&pk, circuit, &mut rng).unwrap();
+    let proof = prover::prove::<_, Groth16<E>, _, _>(&pk, circuit, &mut rng).unwrap();
     println!("[+] Proof has been generated");
 
     // 패딩된 입력 생성
-    let mut padded_inputs: Vec<Fr> = input;
-    padded_inputs.resize(MAX_LEN, Fr::from(0u64)); // Ensure padded length matches
+    let padded_inputs = public_inputs(input_string, MAX_LEN);
 
     // Verify the proof with correct inputs
-    let is_valid = Groth16::<Bn254, LibsnarkReduction>::verify(&vk, &padded_inputs, &proof).unwrap();
-
-    println!("Verification result: {}", is_valid);
+    prover::verify::<_, Groth16<E>>(&vk, &padded_inputs, &proof).unwrap()
 }
 
 /// Reads a line of input from stdin and returns it as a String.
@@ -57,4 +93,3 @@ fn read_input() -> String {
 
     input_string.trim().to_string()  // Remove leading/trailing whitespace
 }
-
@@ -0,0 +1,16 @@
+#![no_main]
+
+// Run with `cargo +nightly fuzz run compile_regex` from the repository root.
+
+use arkworks_regex_circuit::{
+    regex::create_regex_and_dfa_from_str_and_defs, structs::SubstringDefinitionsJson,
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|pattern: &str| {
+    // Compilation may reject the pattern, but it must never panic
+    let _ = create_regex_and_dfa_from_str_and_defs(
+        pattern,
+        SubstringDefinitionsJson { transitions: vec![] },
+    );
+});
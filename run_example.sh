@@ -5,10 +5,12 @@ cargo run
 rm ./example/src/generated_circuit.rs
 cp ./generated_circuit.rs ./example/src/
 
-# run test code
+# run test code (pass a curve name, e.g. `cargo run -- bls12-381`, to change the curve)
 cd example
 cargo run
 
 # return
 cd ..
 
+# alternatively, write a standalone prover crate for a regex and run its tests
+# cargo run -- gen-crate --regex 'a[bc]+d' --out ../regex_prover && (cd ../regex_prover && cargo test)
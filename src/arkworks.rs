@@ -1,214 +1,235 @@
-use crate::structs::DFAGraph;
-
+use crate::{
+    circuit::{ELEMENT_TO_BYTE_SOURCE, PACKED_CHUNK_BYTES},
+    errors::CompilerError,
+    structs::DFAGraph,
+};
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use syn::Ident;
 
 /// Generates the complete Arkworks circuit as a string in Rust.
+///
+/// With `max_len`, the struct also gets a `MAX_LEN` constant of that value.
+///
+/// With `packed_input`, the input bytes are private witnesses and the public inputs are
+/// the bytes packed into 31-byte chunks; otherwise every byte is its own public input.
+///
+/// The code is built as a token stream, checked with `syn::parse_file` and pretty-printed
+/// with `prettyplease`. Returns a `CompilerError::CodegenError` if `struct_name` is not a
+/// Rust identifier or the generated code does not parse.
 pub fn gen_arkworks_allstr(
     dfa_graph: &DFAGraph,
     struct_name: &str,
     regex_str: &str,
-    end_anchor: bool,
-) -> String {
-    let state_len = dfa_graph.states.len();
+    max_len: Option<usize>,
+    packed_input: bool,
+) -> Result<String, CompilerError> {
+    let struct_ident = syn::parse_str::<Ident>(struct_name).map_err(|_| {
+        CompilerError::CodegenError(format!("Invalid struct name \"{}\"", struct_name))
+    })?;
 
     // (1)
-    let declarations = generate_declarations_arkworks(
-        struct_name,
-        regex_str,
-        end_anchor,
-    );
+    let declarations = generate_declarations_arkworks(packed_input);
 
     // (2)
-    let init_code = generate_init_code_arkworks(state_len, 256usize);
+    let init_code = generate_init_code_arkworks(packed_input);
 
     // (3)
-    let transition_logic = generate_state_transition_logic_arkworks(dfa_graph, state_len, end_anchor);
+    let transition_logic = generate_state_transition_logic_arkworks(dfa_graph);
 
     // (4)
-    let accept_logic = generate_accept_logic_arkworks(dfa_graph, end_anchor);
+    let accept_logic = generate_accept_logic_arkworks(dfa_graph);
+
+    // Packed inputs are checked to be bytes by the same function as `DFACircuit`
+    let element_to_byte = packed_input.then(|| {
+        ELEMENT_TO_BYTE_SOURCE
+            .parse::<TokenStream>()
+            .expect("the source of element_to_byte is valid Rust")
+    });
+
+    let max_len_const = max_len.map(|max_len| {
+        let max_len = Literal::usize_unsuffixed(max_len);
+        quote! {
+            impl<F: PrimeField> #struct_ident<F> {
+                pub const MAX_LEN: usize = #max_len;
+            }
+        }
+    });
 
-    let final_code = [
-        declarations, //////// (1)
-        init_code, /////////// (2)
-        transition_logic, //// (3)
-        accept_logic, //////// (4)
-    ].concat();
+    let doc = format!(" Regex: {}", regex_str.replace('\n', "\\n").replace('\r', "\\r"));
+    let final_code = quote! {
+        #declarations
 
-    final_code.join("\n")
+        #[doc = #doc]
+        #[derive(Clone)]
+        pub struct #struct_ident<F: PrimeField> {
+            pub input: Vec<F>,
+            pub max_len: usize,
+        }
+
+        impl<F: PrimeField> ConstraintSynthesizer<F> for #struct_ident<F> {
+            fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+                #init_code
+                #transition_logic
+                #accept_logic
+            }
+        }
+
+        #max_len_const
+
+        #element_to_byte
+    };
+
+    let file = syn::parse_file(&final_code.to_string()).map_err(|e| {
+        CompilerError::CodegenError(format!("Generated code does not parse: {}", e))
+    })?;
+    Ok(prettyplease::unparse(&file))
 }
 
-fn generate_declarations_arkworks(
-    struct_name: &str,
-    regex_str: &str,
-    _end_anchor: bool,
-) -> Vec<String> {
-    let mut declarations = vec![
-        "use ark_ff::PrimeField;".to_string(),
-        "use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};".to_string(),
-        "use ark_r1cs_std::fields::FieldVar;".to_string(),
-        "use ark_r1cs_std::alloc::AllocVar;".to_string(),
-        "use ark_r1cs_std::boolean::Boolean;".to_string(),
-        "use ark_r1cs_std::fields::fp::FpVar;".to_string(),
-        "use ark_r1cs_std::eq::EqGadget;".to_string(),
-        "".to_string(),
-        format!("/// Regex: {}", regex_str.replace('\n', "\\n").replace('\r', "\\r")),
-        format!("#[derive(Clone)]\npub struct {}<F: PrimeField> {{", struct_name),
-        format!("{}pub input: Vec<F>,", put_space(1)),
-        format!("{}pub max_len: usize,", put_space(1)),
-        "}\n".to_string(),
-        format!("impl<F: PrimeField> ConstraintSynthesizer<F> for {}<F> {{", struct_name),
-        format!("{}fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {{", put_space(1)),
-    ];
-
-    declarations.push(format!("{}// Add state initialization logic here", put_space(2)));
-
-    declarations
+fn generate_declarations_arkworks(packed_input: bool) -> TokenStream {
+    let packed_imports = if packed_input {
+        quote! {
+            use ark_r1cs_std::uint8::UInt8;
+            use ark_r1cs_std::ToBitsGadget;
+        }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        use ark_ff::PrimeField;
+        use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+        use ark_r1cs_std::fields::FieldVar;
+        use ark_r1cs_std::alloc::AllocVar;
+        use ark_r1cs_std::boolean::Boolean;
+        use ark_r1cs_std::fields::fp::FpVar;
+        use ark_r1cs_std::eq::EqGadget;
+        #packed_imports
+    }
 }
 
-fn generate_init_code_arkworks(state_len: usize, max_len: usize) -> Vec<String> {
-    vec![
-        format!("{}// Initialize and pad input variables", put_space(2)),
-        format!("{}let mut padded_input = self.input.clone();", put_space(2)),
-        format!("{}padded_input.resize({}, F::from(0u64));", put_space(2), max_len),
-        format!("{}let input_vars = padded_input", put_space(2)),
-        format!("{}  .into_iter()", put_space(2)),
-        format!("{}  .map(|v| FpVar::new_input(cs.clone(), || Ok(v)))", put_space(2)),
-        format!("{}  .collect::<Result<Vec<_>, _>>()?;", put_space(2)),
-        format!("{}let mut valid = Boolean::constant(true);", put_space(2)),
-        "".to_string(),
-        format!("{}// Initialize state variables ({} states)", put_space(2), state_len),
-    ]
+fn generate_init_code_arkworks(packed_input: bool) -> TokenStream {
+    // Input bytes are witnesses; the public inputs are packed chunks
+    let input_vars = if packed_input {
+        quote! {
+            let input_bytes = padded_input
+                .iter()
+                .map(|v| element_to_byte(v).ok_or(SynthesisError::Unsatisfiable))
+                .collect::<Result<Vec<_>, _>>()?;
+            let input_byte_vars = UInt8::new_witness_vec(cs.clone(), &input_bytes)?;
+            for (chunk, chunk_vars) in input_bytes
+                .chunks(#PACKED_CHUNK_BYTES)
+                .zip(input_byte_vars.chunks(#PACKED_CHUNK_BYTES))
+            {
+                let packed_var = FpVar::new_input(cs.clone(), || Ok(F::from_le_bytes_mod_order(chunk)))?;
+                packed_var.enforce_equal(&Boolean::le_bits_to_fp_var(&chunk_vars.to_bits_le()?)?)?;
+            }
+            let input_vars = input_byte_vars
+                .iter()
+                .map(|byte| Boolean::le_bits_to_fp_var(&byte.to_bits_le()?))
+                .collect::<Result<Vec<_>, _>>()?;
+        }
+    } else {
+        quote! {
+            let input_vars = padded_input
+                .into_iter()
+                .map(|v| FpVar::new_input(cs.clone(), || Ok(v)))
+                .collect::<Result<Vec<_>, _>>()?;
+        }
+    };
+
+    // A longer input is rejected: truncating it would prove that only a prefix matches
+    quote! {
+        if self.input.len() > self.max_len {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+        let mut padded_input = self.input.clone();
+        padded_input.resize(self.max_len, F::from(0u64));
+        #input_vars
+        let mut valid = Boolean::constant(true);
+        let mut padding_started = Boolean::constant(false);
+    }
 }
 
 /// Generates the state transition logic for the Arkworks circuit in Rust.
-fn generate_state_transition_logic_arkworks(
-    dfa_graph: &DFAGraph,
-    _state_len: usize,
-    _end_anchor: bool,
-) -> Vec<String> {
-    let mut lines = vec![];
-
-    // 현재 상태 변수 초기화 (초기 상태는 DFA의 시작 상태, 일반적으로 state_id 0)
-    lines.push(format!("{}// 현재 상태 초기화", put_space(2)));
-    lines.push(format!("{}let mut current_state = FpVar::constant(F::from(0u64));", put_space(2))); // 초기 상태 설정
-
-    // 기본 상태 전이 로직을 추가
-    lines.push(format!("{}// 각 입력 인덱스에 대한 전이 로직", put_space(2)));
-    lines.push(format!("{}for (index, current_input) in input_vars.iter().enumerate() {{", put_space(2)));
-    lines.push(format!("{}let is_padded = current_input.is_eq(&FpVar::constant(F::from(0u64)))?;", put_space(3)));
-    lines.push(format!("{}let cond_padded = is_padded.not();", put_space(3)));
-    lines.push(format!("{}let mut next_state = current_state.clone();", put_space(3)));
-    lines.push(format!("{}let mut has_transitioned = Boolean::constant(false);", put_space(3)));
-
-    // DFA 그래프에서 각 상태와 전이에 대해 로직 생성
-    let mut condition_counter = 0; // 고유한 조건 변수명을 만들기 위한 카운터
+fn generate_state_transition_logic_arkworks(dfa_graph: &DFAGraph) -> TokenStream {
+    let mut transitions = vec![];
+
+    // Counter giving every transition condition a unique variable name
+    let mut condition_counter = 0usize;
     for state in &dfa_graph.states {
-        let from_state = state.state_id;
+        if state.transitions.is_empty() {
+            continue;
+        }
 
-        // 현재 상태 조건 추가
-        lines.push(format!(
-            "\n{}let is_state_{} = current_state.is_eq(&FpVar::constant(F::from({}u64)))?;",
-            put_space(3),
-            from_state,
-            from_state
-        ));
+        let from_state = state.state_id as u64;
+        let is_state = format_ident!("is_state_{}", state.state_id);
+        transitions.push(quote! {
+            let #is_state = current_state.is_eq(&FpVar::constant(F::from(#from_state)))?;
+        });
 
         for (&to_state, char_set) in &state.transitions {
-            // 각 문자의 조건을 추가 (변수명 중복 방지를 위해 고유한 이름 부여)
             condition_counter += 1;
-            let condition_var = format!("cond_{}", condition_counter);
-
-            // 논리적 OR 처리를 위해 Arkworks 메서드 사용
-            let conditions = char_set
-                .iter()
-                .map(|&c| format!("current_input.is_eq(&FpVar::constant(F::from({}u64)))?", c))
-                .collect::<Vec<_>>();
-
-            // 여러 조건을 하나로 합치는 논리 연산 처리
-            let or_conditions = if conditions.len() > 1 {
-                let mut combined_condition = format!("{}", conditions[0]);
-                for condition in &conditions[1..] {
-                    combined_condition = format!(
-                        "{}.or(&{})?", 
-                        combined_condition, 
-                        condition
-                    );
-                }
-                combined_condition
-            } else {
-                conditions[0].clone() // 하나의 조건일 때는 그냥 조건 그대로 사용
-            };
-
-            lines.push(format!(
-                "{}let {} = is_state_{}.and(&({}))?;", 
-                put_space(3),
-                condition_var, 
-                from_state, 
-                or_conditions
-            ));
-
-            // 상태 전이 로직 추가
-            lines.push(format!(
-                "{}next_state = {}.select(&FpVar::constant(F::from({}u64)), &next_state)?;",
-                put_space(3),
-                condition_var,
-                to_state
-            ));
-
-            lines.push(format!("{}has_transitioned = has_transitioned.or(&{})?;", put_space(3), condition_var));
+            let condition_var = format_ident!("cond_{}", condition_counter);
+
+            // One equality check per byte of the transition, ORed together; a transition
+            // without bytes is never taken, as in `DFACircuit`
+            let mut chars = char_set.iter().map(|&c| {
+                let c = c as u64;
+                quote! { current_input.is_eq(&FpVar::constant(F::from(#c)))? }
+            });
+            let first = chars.next().unwrap_or_else(|| quote! { Boolean::constant(false) });
+            let or_conditions = chars.fold(first, |combined, condition| {
+                quote! { #combined.or(&#condition)? }
+            });
+
+            let to_state = to_state as u64;
+            transitions.push(quote! {
+                let #condition_var = #is_state.and(&(#or_conditions))?;
+                next_state = #condition_var.select(&FpVar::constant(F::from(#to_state)), &next_state)?;
+                has_transitioned = has_transitioned.or(&#condition_var)?;
+            });
         }
     }
 
-    // 유효하지 않은 전이 확인 (패딩이 아닐 때)
-    lines.push(format!(
-        "{}let invalid_transition = cond_padded.and(&has_transitioned.not())?;", put_space(3)
-    ));
-    lines.push(format!("{}valid = valid.and(&invalid_transition.not())?;", put_space(3)));
+    // The run starts in state 0; a byte other than padding must take a transition, and
+    // padding may only be followed by more padding
+    quote! {
+        let mut current_state = FpVar::constant(F::from(0u64));
+        for current_input in input_vars.iter() {
+            let is_padded = current_input.is_eq(&FpVar::constant(F::from(0u64)))?;
+            let cond_padded = is_padded.not();
+            let mut next_state = current_state.clone();
+            let mut has_transitioned = Boolean::constant(false);
 
-    // 다음 상태로 업데이트
-    lines.push(format!("{}current_state = next_state;", put_space(3)));
-    lines.push(format!("{}}}", put_space(2)));
+            #(#transitions)*
 
-    lines
-}
+            let invalid_transition = cond_padded.and(&has_transitioned.not())?;
+            valid = valid.and(&invalid_transition.not())?;
 
-/// Generates the acceptance logic for the Arkworks circuit in Rust.
-fn generate_accept_logic_arkworks(
-    dfa_graph: &DFAGraph,
-    _end_anchor: bool,
-) -> Vec<String> {
-    let mut lines = vec![];
+            valid = valid.and(&padding_started.and(&cond_padded)?.not())?;
+            padding_started = padding_started.or(&is_padded)?;
 
-    // 수락 상태가 있는지 확인
-    lines.push(format!("{}// Acceptance logic", put_space(2)));
-    lines.push(format!("{}let mut is_accepting = Boolean::constant(false);", put_space(2)));
+            current_state = next_state;
+        }
+    }
+}
 
-    // DFA 그래프에서 수락 상태를 찾아 동적으로 추가
-    let accept_states: Vec<_> = dfa_graph
+/// Generates the acceptance logic for the Arkworks circuit in Rust.
+fn generate_accept_logic_arkworks(dfa_graph: &DFAGraph) -> TokenStream {
+    let accept_states = dfa_graph
         .states
         .iter()
         .filter(|s| s.state_type == "accept")
-        .map(|s| s.state_id)
-        .collect();
-
-    // 수락 상태 중 하나라도 만족하면 수락 상태로 처리
-    for &accept_state in &accept_states {
-        lines.push(format!(
-            "{}is_accepting = is_accepting.or(&current_state.is_eq(&FpVar::constant(F::from({}u64)))?)?;",
-            put_space(2),
-            accept_state
-        ));
+        .map(|s| s.state_id as u64);
+
+    quote! {
+        let mut is_accepting = Boolean::constant(false);
+        #(
+            is_accepting = is_accepting.or(&current_state.is_eq(&FpVar::constant(F::from(#accept_states)))?)?;
+        )*
+        valid = valid.and(&is_accepting)?;
+        valid.enforce_equal(&Boolean::constant(true))?;
+        Ok(())
     }
-
-    lines.push(format!("{}valid = valid.and(&is_accepting)?;", put_space(2)));
-    lines.push(format!("{}valid.enforce_equal(&Boolean::constant(true))?;", put_space(2)));
-    lines.push(format!("{}Ok(())", put_space(2)));
-    lines.push(format!("{}}}}}", put_space(1)));
-
-    lines
-}
-
-/// Returns a string with two spaces for each level of indentation.
-fn put_space(indent_level: usize) -> String {
-    "  ".repeat(indent_level)  // 두 칸의 공백을 indent_level 만큼 반복하여 생성
 }
-
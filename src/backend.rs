@@ -0,0 +1,358 @@
+use crate::{
+    arkworks::gen_arkworks_allstr,
+    circom::gen_circom_allstr,
+    errors::CompilerError,
+    noir::gen_noir_allstr,
+    structs::{DFAGraph, DFAStateNode},
+};
+use std::{collections::BTreeSet, fmt, str::FromStr};
+
+/// What part of the input has to match the regex.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchMode {
+    /// The whole input, up to its padding, matches.
+    Full,
+    /// Some prefix of the input matches; whatever follows it is ignored.
+    Prefix,
+}
+
+impl MatchMode {
+    /// Every match mode, in the order they are listed by the CLI.
+    pub const ALL: [MatchMode; 2] = [MatchMode::Full, MatchMode::Prefix];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            MatchMode::Full => "full",
+            MatchMode::Prefix => "prefix",
+        }
+    }
+}
+
+impl fmt::Display for MatchMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for MatchMode {
+    type Err = CompilerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MatchMode::ALL
+            .into_iter()
+            .find(|mode| mode.name() == s.to_ascii_lowercase())
+            .ok_or_else(|| CompilerError::GenericError(format!("Unsupported match mode: {}", s)))
+    }
+}
+
+/// How the input is exposed as public inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputEncoding {
+    /// Every byte is its own public input.
+    Bytes,
+    /// The bytes are private and exposed packed into 31-byte public inputs.
+    Packed,
+}
+
+impl InputEncoding {
+    /// Every encoding, in the order they are listed by the CLI.
+    pub const ALL: [InputEncoding; 2] = [InputEncoding::Bytes, InputEncoding::Packed];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            InputEncoding::Bytes => "bytes",
+            InputEncoding::Packed => "packed",
+        }
+    }
+}
+
+impl fmt::Display for InputEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for InputEncoding {
+    type Err = CompilerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        InputEncoding::ALL
+            .into_iter()
+            .find(|encoding| encoding.name() == s.to_ascii_lowercase())
+            .ok_or_else(|| CompilerError::GenericError(format!("Unsupported encoding: {}", s)))
+    }
+}
+
+/// Options shared by every `CircuitBackend`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackendOptions {
+    /// Name of the circuit struct or template; Noir derives its function names from it.
+    pub struct_name: String,
+    /// Maximum input length; when set, backends also emit an entry point of that length.
+    pub max_len: Option<usize>,
+    pub match_mode: MatchMode,
+    pub encoding: InputEncoding,
+    /// One set of `(from, to)` transitions per substring to reveal.
+    pub reveal: Vec<BTreeSet<(usize, usize)>>,
+}
+
+impl Default for BackendOptions {
+    fn default() -> Self {
+        Self {
+            struct_name: "RegexCircuit".to_string(),
+            max_len: None,
+            match_mode: MatchMode::Full,
+            encoding: InputEncoding::Bytes,
+            reveal: vec![],
+        }
+    }
+}
+
+/// A code generator turning a `DFAGraph` into a circuit for some proving system.
+///
+/// Implement this trait to add a target; `backend_by_name` only knows the built-in ones.
+pub trait CircuitBackend {
+    /// The name the CLI selects this backend by.
+    fn name(&self) -> &'static str;
+
+    /// The extension of the files this backend generates, without the dot.
+    fn file_extension(&self) -> &'static str;
+
+    /// Generates the circuit source of `dfa_graph`, after applying `options.match_mode`.
+    ///
+    /// Returns a `CompilerError::CodegenError` if the backend does not support one of the
+    /// options.
+    fn generate(
+        &self,
+        dfa_graph: &DFAGraph,
+        regex_str: &str,
+        options: &BackendOptions,
+    ) -> Result<String, CompilerError>;
+}
+
+/// Emits arkworks `ConstraintSynthesizer` implementations, see `gen_arkworks_allstr`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ArkworksBackend;
+
+/// Emits circom templates, see `gen_circom_allstr`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CircomBackend;
+
+/// Emits Noir functions, see `gen_noir_allstr`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoirBackend;
+
+impl CircuitBackend for ArkworksBackend {
+    fn name(&self) -> &'static str {
+        "arkworks"
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "rs"
+    }
+
+    fn generate(
+        &self,
+        dfa_graph: &DFAGraph,
+        regex_str: &str,
+        options: &BackendOptions,
+    ) -> Result<String, CompilerError> {
+        if !options.reveal.is_empty() {
+            return Err(unsupported(self, "revealing substrings"));
+        }
+        let dfa_graph = apply_match_mode(dfa_graph, options.match_mode);
+
+        gen_arkworks_allstr(
+            &dfa_graph,
+            &options.struct_name,
+            regex_str,
+            options.max_len,
+            options.encoding == InputEncoding::Packed,
+        )
+    }
+}
+
+impl CircuitBackend for CircomBackend {
+    fn name(&self) -> &'static str {
+        "circom"
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "circom"
+    }
+
+    fn generate(
+        &self,
+        dfa_graph: &DFAGraph,
+        regex_str: &str,
+        options: &BackendOptions,
+    ) -> Result<String, CompilerError> {
+        if options.encoding != InputEncoding::Bytes {
+            return Err(unsupported(self, "packed inputs"));
+        }
+        if !is_circom_identifier(&options.struct_name) {
+            return Err(CompilerError::CodegenError(format!(
+                "Invalid template name \"{}\"",
+                options.struct_name
+            )));
+        }
+        let dfa_graph = apply_match_mode(dfa_graph, options.match_mode);
+
+        let mut code =
+            gen_circom_allstr(&dfa_graph, &options.struct_name, regex_str, &options.reveal);
+        if let Some(max_len) = options.max_len {
+            code.push_str(&format!(
+                "\ncomponent main {{public [in]}} = {}({});\n",
+                options.struct_name, max_len
+            ));
+        }
+        Ok(code)
+    }
+}
+
+impl CircuitBackend for NoirBackend {
+    fn name(&self) -> &'static str {
+        "noir"
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "nr"
+    }
+
+    fn generate(
+        &self,
+        dfa_graph: &DFAGraph,
+        regex_str: &str,
+        options: &BackendOptions,
+    ) -> Result<String, CompilerError> {
+        if options.encoding != InputEncoding::Bytes {
+            return Err(unsupported(self, "packed inputs"));
+        }
+        let name = noir_function_prefix(&options.struct_name)?;
+        let dfa_graph = apply_match_mode(dfa_graph, options.match_mode);
+
+        let mut code = gen_noir_allstr(&dfa_graph, &name, regex_str, &options.reveal);
+        // The input is public, as in the arkworks and circom circuits
+        if let Some(max_len) = options.max_len {
+            if options.reveal.is_empty() {
+                code.push_str(&format!(
+                    "\nfn main(input: pub [u8; {}]) {{\n    assert({}_match(input));\n}}\n",
+                    max_len, name
+                ));
+            } else {
+                code.push_str(&format!(
+                    "\nfn main(input: pub [u8; {}]) -> pub [[u8; {}]; {}] {{\n    \
+                     assert({}_match(input));\n    {}_reveal(input)\n}}\n",
+                    max_len,
+                    max_len,
+                    options.reveal.len(),
+                    name,
+                    name
+                ));
+            }
+        }
+        Ok(code)
+    }
+}
+
+/// Keywords circom does not accept as template names.
+const CIRCOM_KEYWORDS: &[&str] = &[
+    "signal", "input", "output", "public", "template", "component", "parallel", "custom", "bus",
+    "var", "function", "return", "if", "else", "for", "while", "do", "log", "assert", "include",
+    "pragma",
+];
+
+/// Returns whether `name` is a circom identifier that is not a keyword.
+///
+/// Identifiers are made of ASCII letters, digits, `_` and `$`, contain a letter and do not
+/// start with a digit.
+fn is_circom_identifier(name: &str) -> bool {
+    let valid_chars = name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    let starts_with_digit = name.starts_with(|c: char| c.is_ascii_digit());
+    valid_chars
+        && !starts_with_digit
+        && name.chars().any(|c| c.is_ascii_alphabetic())
+        && !CIRCOM_KEYWORDS.contains(&name)
+}
+
+/// Returns the prefix of the Noir functions emitted for the circuit called `struct_name`.
+///
+/// The name is converted to snake case and a trailing `_circuit` is dropped, so the default
+/// `RegexCircuit` emits `regex_match` and `regex_reveal`. Returns a
+/// `CompilerError::CodegenError` if `struct_name` is not an ASCII identifier.
+fn noir_function_prefix(struct_name: &str) -> Result<String, CompilerError> {
+    let valid_chars = struct_name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    let starts_with_digit = struct_name.starts_with(|c: char| c.is_ascii_digit());
+    if struct_name.is_empty() || !valid_chars || starts_with_digit {
+        return Err(CompilerError::CodegenError(format!(
+            "Invalid function name prefix \"{}\"",
+            struct_name
+        )));
+    }
+
+    let mut prefix = String::new();
+    let mut previous: Option<char> = None;
+    for c in struct_name.chars() {
+        if c.is_ascii_uppercase()
+            && previous.is_some_and(|p| p.is_ascii_lowercase() || p.is_ascii_digit())
+        {
+            prefix.push('_');
+        }
+        prefix.push(c.to_ascii_lowercase());
+        previous = Some(c);
+    }
+    match prefix.strip_suffix("_circuit") {
+        Some(stripped) if !stripped.is_empty() => Ok(stripped.to_string()),
+        _ => Ok(prefix),
+    }
+}
+
+fn unsupported(backend: &dyn CircuitBackend, feature: &str) -> CompilerError {
+    CompilerError::CodegenError(format!(
+        "The {} backend does not support {}",
+        backend.name(),
+        feature
+    ))
+}
+
+/// Returns every built-in backend.
+pub fn backends() -> Vec<Box<dyn CircuitBackend>> {
+    vec![Box::new(ArkworksBackend), Box::new(CircomBackend), Box::new(NoirBackend)]
+}
+
+/// Returns the built-in backend called `name`.
+pub fn backend_by_name(name: &str) -> Result<Box<dyn CircuitBackend>, CompilerError> {
+    backends()
+        .into_iter()
+        .find(|backend| backend.name() == name.to_ascii_lowercase())
+        .ok_or_else(|| CompilerError::CodegenError(format!("Unsupported backend: {}", name)))
+}
+
+/// Rewrites `dfa_graph` so that matching it in `MatchMode::Full` matches the original in
+/// `match_mode`.
+///
+/// # Function Behavior
+///
+/// - `MatchMode::Full` returns the graph unchanged.
+/// - `MatchMode::Prefix` sends every accept state, on every byte but padding, to a new
+///   accept state looping on every byte, so anything may follow the first match.
+pub fn apply_match_mode(dfa_graph: &DFAGraph, match_mode: MatchMode) -> DFAGraph {
+    let mut dfa_graph = dfa_graph.clone();
+    if match_mode == MatchMode::Full {
+        return dfa_graph;
+    }
+
+    let sink = dfa_graph.states.iter().map(|s| s.state_id + 1).max().unwrap_or(0);
+    let any_byte: BTreeSet<u8> = (1..=u8::MAX).collect();
+    for state in &mut dfa_graph.states {
+        if state.state_type == "accept" {
+            state.transitions = [(sink, any_byte.clone())].into();
+        }
+    }
+    dfa_graph.states.push(DFAStateNode {
+        state_type: "accept".to_string(),
+        state_id: sink,
+        transitions: [(sink, any_byte)].into(),
+    });
+    dfa_graph
+}
@@ -0,0 +1,235 @@
+use crate::{errors::CompilerError, structs::DFAGraph};
+use num_bigint::BigUint;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The number of strings a `DFAGraph` accepts, by length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguageSize {
+    /// `counts[n]` is the number of accepted strings of exactly `n` bytes.
+    pub counts: Vec<BigUint>,
+}
+
+impl LanguageSize {
+    /// The number of accepted strings of at most `counts.len() - 1` bytes.
+    pub fn total(&self) -> BigUint {
+        self.counts.iter().sum()
+    }
+
+    /// `log2` of `total`, the entropy in bits of a uniformly chosen accepted string.
+    pub fn log2_total(&self) -> f64 {
+        log2(&self.total())
+    }
+}
+
+/// How much revealing the substrings of an input discloses about it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Leakage {
+    /// The number of accepted strings that fit in the circuit.
+    pub language_size: BigUint,
+    /// The number of those strings with the same revealed substrings as the input.
+    pub consistent: BigUint,
+}
+
+impl Leakage {
+    /// The entropy in bits left about the input once its substrings are revealed.
+    pub fn remaining_bits(&self) -> f64 {
+        log2(&self.consistent)
+    }
+
+    /// The entropy in bits the revealed substrings disclose, out of `log2(language_size)`.
+    pub fn disclosed_bits(&self) -> f64 {
+        log2(&self.language_size) - log2(&self.consistent)
+    }
+
+    /// Whether the revealed substrings determine the whole input.
+    pub fn discloses_input(&self) -> bool {
+        self.consistent == BigUint::from(1u32)
+    }
+}
+
+/// Returns `log2(n)`, or negative infinity for 0.
+pub fn log2(n: &BigUint) -> f64 {
+    let bits = n.bits();
+    if bits <= 64 {
+        return (n.iter_u64_digits().next().unwrap_or(0) as f64).log2();
+    }
+    // Keep the 64 leading bits, which is all an f64 can hold anyway
+    let shift = bits - 64;
+    let leading = (n >> shift).iter_u64_digits().next().unwrap_or(0);
+    (leading as f64).log2() + shift as f64
+}
+
+impl DFAGraph {
+    /// Counts the strings of up to `max_len` bytes the graph accepts, by length.
+    ///
+    /// Byte 0 is padding and never part of a string, as in `DFAGraph::run`.
+    pub fn count_accepted(&self, max_len: usize) -> LanguageSize {
+        let mut paths: BTreeMap<usize, BigUint> = BTreeMap::from([(0, BigUint::from(1u32))]);
+        let mut counts = vec![];
+        for len in 0..=max_len {
+            counts.push(
+                paths
+                    .iter()
+                    .filter(|(&state_id, _)| self.is_accepting(state_id))
+                    .map(|(_, count)| count)
+                    .sum(),
+            );
+            if len == max_len {
+                break;
+            }
+
+            let mut next: BTreeMap<usize, BigUint> = BTreeMap::new();
+            for (state_id, count) in &paths {
+                for (to_state, bytes) in self.moves(*state_id) {
+                    *next.entry(to_state).or_default() += count * bytes.len();
+                }
+            }
+            paths = next;
+        }
+        LanguageSize { counts }
+    }
+
+    /// Computes the substrings the circuits reveal for `input`, padded to `max_len` bytes.
+    ///
+    /// # Returns
+    ///
+    /// One row of `max_len` bytes per set of `substring_ranges`, holding byte `i` of the
+    /// padded input where it takes a transition of the set and 0 elsewhere, as the
+    /// `reveal` outputs of the circom and Noir backends.
+    pub fn reveal(
+        &self,
+        input: &[u8],
+        max_len: usize,
+        substring_ranges: &[BTreeSet<(usize, usize)>],
+    ) -> Vec<Vec<u8>> {
+        let mut padded = input.to_vec();
+        padded.resize(max_len, 0);
+        let trace = self.run(&padded);
+
+        substring_ranges
+            .iter()
+            .map(|ranges| {
+                padded
+                    .iter()
+                    .zip(trace.states.windows(2))
+                    .map(|(&byte, step)| {
+                        if byte != 0 && ranges.contains(&(step[0], step[1])) {
+                            byte
+                        } else {
+                            0
+                        }
+                    })
+                    .chain(std::iter::repeat(0))
+                    .take(max_len)
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Counts the strings of up to `max_len` bytes the graph accepts whose `reveal` is
+    /// `revealed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_len` - The maximum input length of the circuit.
+    /// * `substring_ranges` - One set of `(from, to)` transitions per revealed substring.
+    /// * `revealed` - One row of `max_len` bytes per set, as returned by `reveal`.
+    ///
+    /// # Returns
+    ///
+    /// The count, or a `CompilerError::GenericError` if `revealed` does not have one row of
+    /// `max_len` bytes per set of `substring_ranges`.
+    pub fn count_consistent(
+        &self,
+        max_len: usize,
+        substring_ranges: &[BTreeSet<(usize, usize)>],
+        revealed: &[Vec<u8>],
+    ) -> Result<BigUint, CompilerError> {
+        if revealed.len() != substring_ranges.len()
+            || revealed.iter().any(|row| row.len() != max_len)
+        {
+            return Err(CompilerError::GenericError(format!(
+                "Expected {} revealed rows of {} bytes",
+                substring_ranges.len(),
+                max_len
+            )));
+        }
+
+        // The input may end at `len` only if nothing is revealed from there on
+        let mut may_end = vec![true; max_len + 1];
+        for len in (0..max_len).rev() {
+            may_end[len] = may_end[len + 1] && revealed.iter().all(|row| row[len] == 0);
+        }
+
+        let mut paths: BTreeMap<usize, BigUint> = BTreeMap::from([(0, BigUint::from(1u32))]);
+        let mut consistent = BigUint::default();
+        for (offset, &may_end_here) in may_end.iter().enumerate() {
+            if may_end_here {
+                consistent += paths
+                    .iter()
+                    .filter(|(&state_id, _)| self.is_accepting(state_id))
+                    .map(|(_, count)| count)
+                    .sum::<BigUint>();
+            }
+            if offset == max_len {
+                break;
+            }
+
+            let mut next: BTreeMap<usize, BigUint> = BTreeMap::new();
+            for (state_id, count) in &paths {
+                for (to_state, bytes) in self.moves(*state_id) {
+                    // Every row revealing this transition must show the same byte, and the
+                    // others nothing
+                    let mut shown = BTreeSet::new();
+                    let mut hidden_everywhere = true;
+                    for (ranges, row) in substring_ranges.iter().zip(revealed) {
+                        if ranges.contains(&(*state_id, to_state)) {
+                            shown.insert(row[offset]);
+                        } else {
+                            hidden_everywhere &= row[offset] == 0;
+                        }
+                    }
+                    let choices = match shown.into_iter().collect::<Vec<_>>().as_slice() {
+                        _ if !hidden_everywhere => 0,
+                        [] => bytes.len(),
+                        [byte] => bytes.contains(byte) as usize,
+                        _ => 0,
+                    };
+                    if choices > 0 {
+                        *next.entry(to_state).or_default() += count * choices;
+                    }
+                }
+            }
+            paths = next;
+        }
+        Ok(consistent)
+    }
+
+    /// Measures how much revealing the substrings of `input` discloses about it.
+    ///
+    /// # Returns
+    ///
+    /// The number of accepted strings of up to `max_len` bytes, and how many of them reveal
+    /// the same substrings as `input`, or a `CompilerError::InputRejected` if `input` is
+    /// longer than `max_len` or not accepted.
+    pub fn leakage(
+        &self,
+        input: &[u8],
+        max_len: usize,
+        substring_ranges: &[BTreeSet<(usize, usize)>],
+    ) -> Result<Leakage, CompilerError> {
+        if input.len() > max_len {
+            return Err(CompilerError::InputRejected(format!(
+                "input is longer than {} bytes",
+                max_len
+            )));
+        }
+        self.check(input)?;
+
+        let revealed = self.reveal(input, max_len, substring_ranges);
+        Ok(Leakage {
+            language_size: self.count_accepted(max_len).total(),
+            consistent: self.count_consistent(max_len, substring_ranges, &revealed)?,
+        })
+    }
+}
@@ -0,0 +1,381 @@
+use crate::{
+    circuit::{dfa_run, PACKED_CHUNK_BYTES},
+    commitment::{hash, hash_gadget},
+    errors::CompilerError,
+    prover,
+    structs::DFAGraph,
+};
+use ark_crypto_primitives::sponge::Absorb;
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    alloc::AllocVar,
+    boolean::Boolean,
+    eq::EqGadget,
+    fields::{fp::FpVar, FieldVar},
+    uint8::UInt8,
+    ToBitsGadget,
+};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use ark_snark::SNARK;
+use ark_std::rand::{CryptoRng, RngCore};
+
+/// A circuit that runs `chunk_len` bytes of a longer input through a `DFAGraph`.
+///
+/// The chunk bytes and the `salt` are private witnesses. The public inputs are, in order,
+/// the state the chunk starts in, the state it ends in, whether the chunk ends in padding,
+/// the commitment of the previous chunk and the commitment of this chunk returned by
+/// `chunk_commitment`. Acceptance is not checked here; chaining chunks with
+/// `verify_chunks` checks that the last one ends in an accept state.
+#[derive(Clone)]
+pub struct ChunkCircuit<F: PrimeField> {
+    pub dfa: DFAGraph,
+    pub chunk: Vec<u8>,
+    pub chunk_len: usize,
+    pub start_state: usize,
+    pub end_state: usize,
+    pub ends_in_padding: bool,
+    pub previous_commitment: F,
+    pub salt: F,
+    pub commitment: F,
+}
+
+impl<F: PrimeField + Absorb> ChunkCircuit<F> {
+    /// Creates the circuit for `chunk` starting in `start_state`, computing its public outputs.
+    ///
+    /// `previous_commitment` is the commitment of the previous chunk, or zero for the first
+    /// one, and `salt` the blinding shared by every chunk of the input.
+    ///
+    /// Returns a `CompilerError` if `chunk_len` is 0, `chunk` is longer than `chunk_len` or
+    /// `chunk` does not run through `dfa` from `start_state`.
+    pub fn new(
+        dfa: DFAGraph,
+        chunk: &[u8],
+        chunk_len: usize,
+        start_state: usize,
+        previous_commitment: F,
+        salt: F,
+    ) -> Result<Self, CompilerError> {
+        // An empty chunk would leave `ends_in_padding` unconstrained
+        if chunk_len == 0 {
+            return Err(CompilerError::GenericError(
+                "Chunk length must be positive".to_string(),
+            ));
+        }
+        if chunk.len() > chunk_len {
+            return Err(CompilerError::GenericError(format!(
+                "Chunk of {} bytes exceeds the chunk length {}",
+                chunk.len(),
+                chunk_len
+            )));
+        }
+        let end_state = run_chunk(&dfa, start_state, chunk)?;
+        Ok(Self {
+            commitment: chunk_commitment(chunk, chunk_len, previous_commitment, salt),
+            dfa,
+            chunk: chunk.to_vec(),
+            chunk_len,
+            start_state,
+            end_state,
+            // Chunks are zero-padded, and padding may only trail a chunk
+            ends_in_padding: chunk.len() < chunk_len || chunk.last() == Some(&0),
+            previous_commitment,
+            salt,
+        })
+    }
+
+    /// Returns the public inputs of this chunk, see `chunk_public_inputs`.
+    pub fn public_inputs(&self) -> Vec<F> {
+        chunk_public_inputs(
+            self.start_state,
+            self.end_state,
+            self.ends_in_padding,
+            self.previous_commitment,
+            self.commitment,
+        )
+    }
+}
+
+/// Builds the public inputs
+/// `[start_state, end_state, ends_in_padding, previous_commitment, commitment]` of a chunk.
+pub fn chunk_public_inputs<F: PrimeField>(
+    start_state: usize,
+    end_state: usize,
+    ends_in_padding: bool,
+    previous_commitment: F,
+    commitment: F,
+) -> Vec<F> {
+    vec![
+        F::from(start_state as u64),
+        F::from(end_state as u64),
+        F::from(ends_in_padding),
+        previous_commitment,
+        commitment,
+    ]
+}
+
+/// Commits to `chunk` zero-padded to `chunk_len` bytes, chained to the previous chunk.
+///
+/// Hashes `salt`, `previous_commitment` and the padded bytes packed into 31-byte
+/// little-endian field elements with Poseidon. The commitment of the last chunk therefore
+/// commits to the whole input, see `chunk_input_commitment`; the private salt keeps a
+/// low-entropy input from being recovered by hashing guesses.
+pub fn chunk_commitment<F: PrimeField + Absorb>(
+    chunk: &[u8],
+    chunk_len: usize,
+    previous_commitment: F,
+    salt: F,
+) -> F {
+    let mut bytes = chunk.to_vec();
+    bytes.resize(chunk_len, 0);
+    let mut elements = vec![salt, previous_commitment];
+    elements.extend(
+        bytes
+            .chunks(PACKED_CHUNK_BYTES)
+            .map(F::from_le_bytes_mod_order),
+    );
+    hash(&elements)
+}
+
+/// Computes the commitment a chain of chunk proofs of `input` ends in, as `prove_chunks`
+/// splits it; this is what `verify_chunks` checks the chain against.
+pub fn chunk_input_commitment<F: PrimeField + Absorb>(
+    input: &[u8],
+    chunk_len: usize,
+    salt: F,
+) -> F {
+    split_input(input, chunk_len)
+        .into_iter()
+        .fold(F::zero(), |previous, chunk| {
+            chunk_commitment(chunk, chunk_len, previous, salt)
+        })
+}
+
+/// Splits `input` into chunks of `chunk_len` bytes; an empty input is one empty chunk.
+fn split_input(input: &[u8], chunk_len: usize) -> Vec<&[u8]> {
+    if input.is_empty() {
+        vec![&[]]
+    } else {
+        input.chunks(chunk_len).collect()
+    }
+}
+
+/// Runs `chunk` through `dfa` natively from `start_state` and returns the state it ends in.
+fn run_chunk(dfa: &DFAGraph, start_state: usize, chunk: &[u8]) -> Result<usize, CompilerError> {
+    let trace = dfa.run_from(start_state, chunk);
+    match trace.stuck_at {
+        Some(offset) => Err(CompilerError::InputRejected(format!(
+            "no transition from state {} on byte {} at offset {} of the chunk",
+            trace.end_state(),
+            chunk[offset],
+            offset
+        ))),
+        None => Ok(trace.end_state()),
+    }
+}
+
+impl<F: PrimeField + Absorb> ConstraintSynthesizer<F> for ChunkCircuit<F> {
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        if self.chunk.len() > self.chunk_len {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+        let start_state = FpVar::new_input(cs.clone(), || Ok(F::from(self.start_state as u64)))?;
+        let end_state = FpVar::new_input(cs.clone(), || Ok(F::from(self.end_state as u64)))?;
+        let ends_in_padding = Boolean::new_input(cs.clone(), || Ok(self.ends_in_padding))?;
+        let previous_commitment = FpVar::new_input(cs.clone(), || Ok(self.previous_commitment))?;
+        let commitment = FpVar::new_input(cs.clone(), || Ok(self.commitment))?;
+
+        let mut chunk = self.chunk.clone();
+        chunk.resize(self.chunk_len, 0);
+        let byte_vars = UInt8::new_witness_vec(cs.clone(), &chunk)?;
+
+        // Bind the private bytes to the public commitment, chained to the previous chunk
+        let mut elements = vec![
+            FpVar::new_witness(cs.clone(), || Ok(self.salt))?,
+            previous_commitment,
+        ];
+        for chunk_vars in byte_vars.chunks(PACKED_CHUNK_BYTES) {
+            elements.push(Boolean::le_bits_to_fp_var(&chunk_vars.to_bits_le()?)?);
+        }
+        hash_gadget(cs.clone(), &elements)?.enforce_equal(&commitment)?;
+
+        let input_vars = byte_vars
+            .iter()
+            .map(|byte| Boolean::le_bits_to_fp_var(&byte.to_bits_le()?))
+            .collect::<Result<Vec<_>, _>>()?;
+        let (current_state, valid) = dfa_run(&self.dfa, start_state, &input_vars)?;
+
+        // Padding only trails a chunk, so it holds padding exactly if its last byte is zero
+        if let Some(last_byte) = input_vars.last() {
+            last_byte.is_zero()?.enforce_equal(&ends_in_padding)?;
+        }
+
+        valid.enforce_equal(&Boolean::constant(true))?;
+        current_state.enforce_equal(&end_state)?;
+        Ok(())
+    }
+}
+
+/// One proven chunk of a long input, with the public inputs it was proven against.
+///
+/// The previous chunk's commitment is not stored: `verify_chunks` takes it from the chain.
+pub struct ChunkProof<F: PrimeField, S: SNARK<F>> {
+    pub start_state: usize,
+    pub end_state: usize,
+    pub ends_in_padding: bool,
+    pub commitment: F,
+    pub proof: S::Proof,
+}
+
+/// Runs the setup of `S` for chunks of `chunk_len` bytes of `dfa`.
+///
+/// The keys only depend on the graph and the chunk length, so they serve every chunk.
+/// Returns a `CompilerError` if `chunk_len` is 0.
+pub fn setup_chunks<F, S, R>(
+    dfa: &DFAGraph,
+    chunk_len: usize,
+    rng: &mut R,
+) -> Result<(S::ProvingKey, S::VerifyingKey), CompilerError>
+where
+    F: PrimeField + Absorb,
+    S: SNARK<F>,
+    R: RngCore + CryptoRng,
+{
+    let circuit = ChunkCircuit::<F>::new(dfa.clone(), &[], chunk_len, 0, F::zero(), F::zero())?;
+    prover::setup::<F, S, _, _>(circuit, rng)
+}
+
+/// Splits `input` into chunks of `chunk_len` bytes and proves each of them.
+///
+/// # Arguments
+///
+/// * `pk` - The proving key returned by `setup_chunks` for the same `dfa` and `chunk_len`.
+/// * `dfa` - The graph of the regex to match.
+/// * `input` - The whole input; an empty input is proven as a single padding chunk.
+/// * `chunk_len` - The number of bytes per chunk; the last chunk is zero-padded.
+/// * `salt` - The private blinding of the chunk commitments; it must be random.
+/// * `rng` - The randomness used by the prover.
+///
+/// # Returns
+///
+/// A `Result` containing one `ChunkProof` per chunk, in input order, or a `CompilerError`
+/// if the input does not run through the graph or proving fails.
+///
+/// # Function Behavior
+///
+/// - The first chunk starts in state 0 and every next chunk starts in the state the
+///   previous one ended in.
+/// - Every chunk commitment is chained to the previous one, starting from zero, so the
+///   last one is the `chunk_input_commitment` of `input`.
+/// - Acceptance of the final state is left to `verify_chunks`.
+pub fn prove_chunks<F, S, R>(
+    pk: &S::ProvingKey,
+    dfa: &DFAGraph,
+    input: &[u8],
+    chunk_len: usize,
+    salt: F,
+    rng: &mut R,
+) -> Result<Vec<ChunkProof<F, S>>, CompilerError>
+where
+    F: PrimeField + Absorb,
+    S: SNARK<F>,
+    R: RngCore + CryptoRng,
+{
+    if chunk_len == 0 {
+        return Err(CompilerError::GenericError(
+            "Chunk length must be positive".to_string(),
+        ));
+    }
+
+    let chunks = split_input(input, chunk_len);
+    let mut proofs = Vec::with_capacity(chunks.len());
+    let mut start_state = 0;
+    let mut previous_commitment = F::zero();
+    for chunk in chunks {
+        let circuit = ChunkCircuit::<F>::new(
+            dfa.clone(),
+            chunk,
+            chunk_len,
+            start_state,
+            previous_commitment,
+            salt,
+        )?;
+        let (end_state, ends_in_padding, commitment) = (
+            circuit.end_state,
+            circuit.ends_in_padding,
+            circuit.commitment,
+        );
+        let proof = prover::prove::<F, S, _, _>(pk, circuit, rng)?;
+        proofs.push(ChunkProof {
+            start_state,
+            end_state,
+            ends_in_padding,
+            commitment,
+            proof,
+        });
+        start_state = end_state;
+        previous_commitment = commitment;
+    }
+    Ok(proofs)
+}
+
+/// Verifies a chain of chunk proofs produced by `prove_chunks` for the input committed to
+/// by `commitment`, its `chunk_input_commitment`.
+///
+/// Returns `Ok(true)` if:
+/// - the chain is non-empty, starts in state 0 and ends in an accept state of `dfa`;
+/// - every chunk starts in the state the previous one ended in;
+/// - the first chunk is chained to zero, every next chunk to the commitment of the previous
+///   one, and the last commitment is `commitment`;
+/// - only the last chunk ends in padding, so no bytes can be skipped between chunks;
+/// - every proof verifies.
+pub fn verify_chunks<F, S>(
+    vk: &S::VerifyingKey,
+    dfa: &DFAGraph,
+    proofs: &[ChunkProof<F, S>],
+    commitment: F,
+) -> Result<bool, CompilerError>
+where
+    F: PrimeField,
+    S: SNARK<F>,
+{
+    let (Some(first), Some(last)) = (proofs.first(), proofs.last()) else {
+        return Ok(false);
+    };
+    if first.start_state != 0 || !dfa.is_accepting(last.end_state) {
+        return Ok(false);
+    }
+    if proofs
+        .windows(2)
+        .any(|pair| pair[0].end_state != pair[1].start_state)
+    {
+        return Ok(false);
+    }
+    if last.commitment != commitment {
+        return Ok(false);
+    }
+    if proofs[..proofs.len() - 1]
+        .iter()
+        .any(|chunk_proof| chunk_proof.ends_in_padding)
+    {
+        return Ok(false);
+    }
+
+    let mut previous_commitment = F::zero();
+    for chunk_proof in proofs {
+        if !prover::verify::<F, S>(
+            vk,
+            &chunk_public_inputs(
+                chunk_proof.start_state,
+                chunk_proof.end_state,
+                chunk_proof.ends_in_padding,
+                previous_commitment,
+                chunk_proof.commitment,
+            ),
+            &chunk_proof.proof,
+        )? {
+            return Ok(false);
+        }
+        previous_commitment = chunk_proof.commitment;
+    }
+    Ok(true)
+}
@@ -0,0 +1,230 @@
+use crate::{codegen::put_space, structs::DFAGraph};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Generates a circom template matching `dfa_graph`, with the semantics of the arkworks circuit.
+///
+/// # Arguments
+///
+/// * `dfa_graph` - The graph to match.
+/// * `template_name` - The name of the emitted template.
+/// * `regex_str` - The regex, recorded in a comment.
+/// * `substring_ranges` - One set of `(from, to)` transitions per substring to reveal.
+///
+/// # Returns
+///
+/// The source of a template `template_name(maxLen)` taking `signal input in[maxLen]` and
+/// exposing one `signal output reveal{j}[maxLen]` per substring.
+///
+/// # Function Behavior
+///
+/// - The current state is one-hot encoded in `states[i]`, in the order of `dfa_graph.states`.
+/// - Byte 0 is padding: it keeps the state and may only be followed by more padding.
+/// - Any other byte without a transition leaves no state active, which is unsatisfiable, as
+///   is ending outside an accept state.
+/// - `reveal{j}[i]` is `in[i]` when byte `i` takes a transition of substring `j`, else 0.
+pub fn gen_circom_allstr(
+    dfa_graph: &DFAGraph,
+    template_name: &str,
+    regex_str: &str,
+    substring_ranges: &[BTreeSet<(usize, usize)>],
+) -> String {
+    let state_index: BTreeMap<usize, usize> = dfa_graph
+        .states
+        .iter()
+        .enumerate()
+        .map(|(index, state)| (state.state_id, index))
+        .collect();
+    let transitions: Vec<(usize, usize, &BTreeSet<u8>)> = dfa_graph
+        .states
+        .iter()
+        .flat_map(|state| {
+            state
+                .transitions
+                .iter()
+                .map(move |(&to_state, char_set)| (state.state_id, to_state, char_set))
+        })
+        .collect();
+    let bytes: Vec<u8> = transitions
+        .iter()
+        .flat_map(|(_, _, char_set)| char_set.iter().copied())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+
+    let final_code = [
+        generate_declarations_circom(
+            dfa_graph,
+            template_name,
+            regex_str,
+            substring_ranges.len(),
+            transitions.len(),
+            bytes.len(),
+        ),
+        generate_init_code_circom(dfa_graph),
+        generate_step_logic_circom(dfa_graph, &state_index, &transitions, &bytes, substring_ranges),
+        generate_accept_logic_circom(dfa_graph),
+    ]
+    .concat();
+
+    final_code.join("\n")
+}
+
+fn generate_declarations_circom(
+    dfa_graph: &DFAGraph,
+    template_name: &str,
+    regex_str: &str,
+    num_substrings: usize,
+    num_transitions: usize,
+    num_bytes: usize,
+) -> Vec<String> {
+    let state_len = dfa_graph.states.len();
+    let mut lines = vec![
+        "pragma circom 2.1.5;".to_string(),
+        "".to_string(),
+        "include \"circomlib/circuits/comparators.circom\";".to_string(),
+        "".to_string(),
+        format!("// Regex: {}", regex_str.replace('\n', "\\n").replace('\r', "\\r")),
+        format!("template {}(maxLen) {{", template_name),
+        format!("{}signal input in[maxLen];", put_space(1)),
+    ];
+    for j in 0..num_substrings {
+        lines.push(format!("{}signal output reveal{}[maxLen];", put_space(1), j));
+    }
+    lines.extend([
+        "".to_string(),
+        format!("{}// One-hot encoding of the state before every byte ({} states)", put_space(1), state_len),
+        format!("{}signal states[maxLen + 1][{}];", put_space(1), state_len),
+        format!("{}signal isPad[maxLen];", put_space(1)),
+    ]);
+    if num_bytes > 0 {
+        lines.push(format!("{}signal isByte[maxLen][{}];", put_space(1), num_bytes));
+    }
+    if num_transitions > 0 {
+        lines.push(format!("{}signal trans[maxLen][{}];", put_space(1), num_transitions));
+    }
+    lines.push(format!("{}signal stay[maxLen][{}];", put_space(1), state_len));
+    lines.push("".to_string());
+    lines
+}
+
+fn generate_init_code_circom(dfa_graph: &DFAGraph) -> Vec<String> {
+    dfa_graph
+        .states
+        .iter()
+        .enumerate()
+        .map(|(index, state)| {
+            format!(
+                "{}states[0][{}] <== {};",
+                put_space(1),
+                index,
+                (state.state_id == 0) as u8
+            )
+        })
+        .collect()
+}
+
+fn generate_step_logic_circom(
+    dfa_graph: &DFAGraph,
+    state_index: &BTreeMap<usize, usize>,
+    transitions: &[(usize, usize, &BTreeSet<u8>)],
+    bytes: &[u8],
+    substring_ranges: &[BTreeSet<(usize, usize)>],
+) -> Vec<String> {
+    let byte_index: BTreeMap<u8, usize> =
+        bytes.iter().enumerate().map(|(index, &byte)| (byte, index)).collect();
+
+    let mut lines = vec![
+        "".to_string(),
+        format!("{}for (var i = 0; i < maxLen; i++) {{", put_space(1)),
+        format!("{}isPad[i] <== IsZero()(in[i]);", put_space(2)),
+    ];
+
+    for (k, byte) in bytes.iter().enumerate() {
+        lines.push(format!("{}isByte[i][{}] <== IsEqual()([in[i], {}]);", put_space(2), k, byte));
+    }
+
+    // At most one transition fires since the graph is deterministic
+    for (e, (from_state, _, char_set)) in transitions.iter().enumerate() {
+        let terms = char_set
+            .iter()
+            .map(|byte| format!("isByte[i][{}]", byte_index[byte]))
+            .collect::<Vec<_>>();
+        let condition = match terms.len() {
+            0 => "0".to_string(),
+            1 => terms[0].clone(),
+            _ => format!("({})", terms.join(" + ")),
+        };
+        lines.push(format!(
+            "{}trans[i][{}] <== states[i][{}] * {};",
+            put_space(2),
+            e,
+            state_index[from_state],
+            condition
+        ));
+    }
+
+    for (index, state) in dfa_graph.states.iter().enumerate() {
+        lines.push(format!("{}stay[i][{}] <== isPad[i] * states[i][{}];", put_space(2), index, index));
+        let mut terms = transitions
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, to_state, _))| *to_state == state.state_id)
+            .map(|(e, _)| format!("trans[i][{}]", e))
+            .collect::<Vec<_>>();
+        terms.push(format!("stay[i][{}]", index));
+        lines.push(format!("{}states[i + 1][{}] <== {};", put_space(2), index, terms.join(" + ")));
+    }
+
+    let active = (0..dfa_graph.states.len())
+        .map(|index| format!("states[i + 1][{}]", index))
+        .collect::<Vec<_>>();
+    lines.push(format!("{}// A byte without a transition leaves no state active", put_space(2)));
+    lines.push(format!("{}{} === 1;", put_space(2), active.join(" + ")));
+
+    for (j, ranges) in substring_ranges.iter().enumerate() {
+        let terms = transitions
+            .iter()
+            .enumerate()
+            .filter(|(_, (from_state, to_state, _))| ranges.contains(&(*from_state, *to_state)))
+            .map(|(e, _)| format!("trans[i][{}]", e))
+            .collect::<Vec<_>>();
+        if terms.is_empty() {
+            lines.push(format!("{}reveal{}[i] <== 0;", put_space(2), j));
+        } else {
+            lines.push(format!("{}reveal{}[i] <== in[i] * ({});", put_space(2), j, terms.join(" + ")));
+        }
+    }
+
+    lines.extend([
+        format!("{}}}", put_space(1)),
+        "".to_string(),
+        format!("{}// Padding may only be followed by more padding", put_space(1)),
+        format!("{}for (var i = 1; i < maxLen; i++) {{", put_space(1)),
+        format!("{}isPad[i - 1] * (1 - isPad[i]) === 0;", put_space(2)),
+        format!("{}}}", put_space(1)),
+    ]);
+    lines
+}
+
+fn generate_accept_logic_circom(dfa_graph: &DFAGraph) -> Vec<String> {
+    let accepting = dfa_graph
+        .states
+        .iter()
+        .enumerate()
+        .filter(|(_, state)| state.state_type == "accept")
+        .map(|(index, _)| format!("states[maxLen][{}]", index))
+        .collect::<Vec<_>>();
+
+    let mut lines = vec![
+        "".to_string(),
+        format!("{}// The run must end in an accept state", put_space(1)),
+    ];
+    if accepting.is_empty() {
+        lines.push(format!("{}0 === 1;", put_space(1)));
+    } else {
+        lines.push(format!("{}{} === 1;", put_space(1), accepting.join(" + ")));
+    }
+    lines.push("}".to_string());
+    lines.push("".to_string());
+    lines
+}
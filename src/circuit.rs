@@ -0,0 +1,258 @@
+use crate::{
+    errors::CompilerError,
+    structs::{DFAGraph, Trace},
+};
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    alloc::AllocVar, boolean::Boolean, eq::EqGadget, fields::fp::FpVar, fields::FieldVar,
+    uint8::UInt8, ToBitsGadget,
+};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+
+/// Number of input bytes packed into one public field element.
+///
+/// 31 bytes (248 bits) fit below the modulus of every supported scalar field.
+pub const PACKED_CHUNK_BYTES: usize = 31;
+
+/// A regex circuit driven by a `DFAGraph` at synthesis time.
+///
+/// It allocates the same variables and emits the same constraints, in the same order,
+/// as the code produced by `gen_arkworks_allstr` for the same graph. This makes it
+/// possible to inspect, export or test a regex circuit without compiling generated code.
+#[derive(Clone)]
+pub struct DFACircuit<F: PrimeField> {
+    pub dfa: DFAGraph,
+    pub input: Vec<F>,
+    pub max_len: usize,
+    pub packed_input: bool,
+}
+
+impl<F: PrimeField> DFACircuit<F> {
+    /// Creates a circuit for `dfa` with `input` encoded one byte per field element.
+    ///
+    /// Every byte is a public input; set `packed_input` to expose packed chunks instead.
+    /// An input longer than `max_len` is never truncated: synthesis fails, and `check_input`
+    /// reports it before proving.
+    pub fn from_bytes(dfa: DFAGraph, input: &[u8], max_len: usize) -> Self {
+        Self {
+            dfa,
+            input: input.iter().map(|&b| F::from(b as u64)).collect(),
+            max_len,
+            packed_input: false,
+        }
+    }
+
+    /// Checks natively that the input fits into `max_len` bytes and matches the graph.
+    ///
+    /// Proving a non-matching input fails only after the expensive setup and proving steps,
+    /// so callers should run this first; the error names the offending byte offset.
+    pub fn check_input(&self) -> Result<Trace, CompilerError> {
+        if self.input.len() > self.max_len {
+            return Err(CompilerError::InputRejected(format!(
+                "input of {} bytes exceeds the maximum length {}",
+                self.input.len(),
+                self.max_len
+            )));
+        }
+        let bytes = self
+            .input
+            .iter()
+            .enumerate()
+            .map(|(offset, v)| {
+                element_to_byte(v).ok_or_else(|| {
+                    CompilerError::InputRejected(format!(
+                        "input element at offset {} is not a byte",
+                        offset
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        self.dfa.check(&bytes)
+    }
+}
+
+/// Defines `$item` and stores its source in the constant `$source`.
+macro_rules! item_with_source {
+    ($source:ident, $item:item) => {
+        $item
+
+        pub(crate) const $source: &str = stringify!($item);
+    };
+}
+
+// `gen_arkworks_allstr` emits this function into packed-input circuits
+item_with_source!(
+    ELEMENT_TO_BYTE_SOURCE,
+    /// Returns the byte `value` encodes, or `None` if it is 256 or more.
+    pub(crate) fn element_to_byte<F: PrimeField>(value: &F) -> Option<u8> {
+        let value = value.into_bigint();
+        if value > F::BigInt::from(u8::MAX as u64) {
+            return None;
+        }
+        Some(value.as_ref()[0] as u8)
+    }
+);
+
+/// Pads `input` to `max_len` and allocates one variable per byte.
+///
+/// Bytes are public inputs, or with `packed_input` private witnesses whose 31-byte chunks
+/// are exposed as public inputs. An input longer than `max_len` is
+/// `SynthesisError::Unsatisfiable`: truncating it would prove that only a prefix matches. So
+/// is a packed input element that is not a byte, rather than being cut to its low byte.
+pub(crate) fn alloc_input_vars<F: PrimeField>(
+    cs: ConstraintSystemRef<F>,
+    input: &[F],
+    max_len: usize,
+    packed_input: bool,
+) -> Result<Vec<FpVar<F>>, SynthesisError> {
+    if input.len() > max_len {
+        return Err(SynthesisError::Unsatisfiable);
+    }
+    // Initialize and pad input variables
+    let mut padded_input = input.to_vec();
+    padded_input.resize(max_len, F::from(0u64));
+    if !packed_input {
+        return padded_input
+            .into_iter()
+            .map(|v| FpVar::new_input(cs.clone(), || Ok(v)))
+            .collect();
+    }
+
+    // Input bytes are witnesses; the public inputs are packed chunks
+    let input_bytes = padded_input
+        .iter()
+        .map(|v| element_to_byte(v).ok_or(SynthesisError::Unsatisfiable))
+        .collect::<Result<Vec<_>, _>>()?;
+    let input_byte_vars = UInt8::new_witness_vec(cs.clone(), &input_bytes)?;
+    for (chunk, chunk_vars) in input_bytes
+        .chunks(PACKED_CHUNK_BYTES)
+        .zip(input_byte_vars.chunks(PACKED_CHUNK_BYTES))
+    {
+        let packed_var = FpVar::new_input(cs.clone(), || Ok(F::from_le_bytes_mod_order(chunk)))?;
+        packed_var.enforce_equal(&Boolean::le_bits_to_fp_var(&chunk_vars.to_bits_le()?)?)?;
+    }
+    input_byte_vars
+        .iter()
+        .map(|byte| Boolean::le_bits_to_fp_var(&byte.to_bits_le()?))
+        .collect()
+}
+
+/// The outcome of applying one input byte in `dfa_step`.
+struct Step<F: PrimeField> {
+    next_state: FpVar<F>,
+    is_valid: Boolean<F>,
+    is_padded: Boolean<F>,
+}
+
+/// Applies one input byte to `current_state`, mirroring one iteration of the generated loop.
+///
+/// A non-padding byte without a matching transition is invalid, while padding (byte 0)
+/// leaves the state unchanged.
+fn dfa_step<F: PrimeField>(
+    dfa: &DFAGraph,
+    current_state: &FpVar<F>,
+    current_input: &FpVar<F>,
+) -> Result<Step<F>, SynthesisError> {
+    let is_padded = current_input.is_eq(&FpVar::constant(F::from(0u64)))?;
+    let cond_padded = is_padded.not();
+    let mut next_state = current_state.clone();
+    let mut has_transitioned = Boolean::constant(false);
+
+    for state in &dfa.states {
+        if state.transitions.is_empty() {
+            continue;
+        }
+
+        let is_state = current_state.is_eq(&FpVar::constant(F::from(state.state_id as u64)))?;
+
+        for (&to_state, char_set) in &state.transitions {
+            let mut chars = char_set.iter();
+            let mut is_char = match chars.next() {
+                Some(&c) => current_input.is_eq(&FpVar::constant(F::from(c as u64)))?,
+                None => Boolean::constant(false),
+            };
+            for &c in chars {
+                is_char = is_char.or(&current_input.is_eq(&FpVar::constant(F::from(c as u64)))?)?;
+            }
+
+            let cond = is_state.and(&is_char)?;
+            next_state = cond.select(&FpVar::constant(F::from(to_state as u64)), &next_state)?;
+            has_transitioned = has_transitioned.or(&cond)?;
+        }
+    }
+
+    let invalid_transition = cond_padded.and(&has_transitioned.not())?;
+    Ok(Step {
+        next_state,
+        is_valid: invalid_transition.not(),
+        is_padded,
+    })
+}
+
+/// Runs `input_vars` through `dfa` from `start_state`, mirroring the generated loop.
+///
+/// Returns the final state and whether every step was valid. Padding may only trail the
+/// input: a non-padding byte after a padding byte is invalid, so zeros cannot be used to
+/// skip over bytes in the middle of a string.
+pub(crate) fn dfa_run<F: PrimeField>(
+    dfa: &DFAGraph,
+    start_state: FpVar<F>,
+    input_vars: &[FpVar<F>],
+) -> Result<(FpVar<F>, Boolean<F>), SynthesisError> {
+    let run = dfa_run_from(dfa, start_state, Boolean::constant(false), input_vars)?;
+    Ok((run.end_state, run.valid))
+}
+
+/// The outcome of running input bytes in `dfa_run_from`.
+pub(crate) struct Run<F: PrimeField> {
+    pub(crate) end_state: FpVar<F>,
+    pub(crate) padding_started: Boolean<F>,
+    pub(crate) valid: Boolean<F>,
+}
+
+/// Like `dfa_run`, but continues a run that has already seen padding if `padding_started`.
+///
+/// Also returns whether padding has started, so that a run split over several calls
+/// rejects bytes after the padding of an earlier call.
+pub(crate) fn dfa_run_from<F: PrimeField>(
+    dfa: &DFAGraph,
+    start_state: FpVar<F>,
+    mut padding_started: Boolean<F>,
+    input_vars: &[FpVar<F>],
+) -> Result<Run<F>, SynthesisError> {
+    let mut valid = Boolean::constant(true);
+
+    let mut current_state = start_state;
+    for current_input in input_vars.iter() {
+        let step = dfa_step(dfa, &current_state, current_input)?;
+        valid = valid.and(&step.is_valid)?;
+        valid = valid.and(&padding_started.and(&step.is_padded.not())?.not())?;
+        padding_started = padding_started.or(&step.is_padded)?;
+        current_state = step.next_state;
+    }
+
+    Ok(Run {
+        end_state: current_state,
+        padding_started,
+        valid,
+    })
+}
+
+impl<F: PrimeField> ConstraintSynthesizer<F> for DFACircuit<F> {
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let input_vars = alloc_input_vars(cs.clone(), &self.input, self.max_len, self.packed_input)?;
+        let (current_state, mut valid) =
+            dfa_run(&self.dfa, FpVar::constant(F::from(0u64)), &input_vars)?;
+
+        // Acceptance logic
+        let mut is_accepting = Boolean::constant(false);
+        for state in self.dfa.states.iter().filter(|s| s.state_type == "accept") {
+            is_accepting = is_accepting
+                .or(&current_state.is_eq(&FpVar::constant(F::from(state.state_id as u64)))?)?;
+        }
+
+        valid = valid.and(&is_accepting)?;
+        valid.enforce_equal(&Boolean::constant(true))?;
+        Ok(())
+    }
+}
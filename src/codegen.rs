@@ -0,0 +1,6 @@
+//! Helpers shared by the line-based code generators of `circom` and `noir`.
+
+/// Returns a string with four spaces for each level of indentation.
+pub(crate) fn put_space(indent_level: usize) -> String {
+    "    ".repeat(indent_level)
+}
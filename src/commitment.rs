@@ -0,0 +1,54 @@
+use ark_crypto_primitives::sponge::{
+    constraints::CryptographicSpongeVar,
+    poseidon::{constraints::PoseidonSpongeVar, find_poseidon_ark_and_mds, PoseidonConfig, PoseidonSponge},
+    Absorb, CryptographicSponge, FieldBasedCryptographicSponge,
+};
+use ark_ff::PrimeField;
+use ark_r1cs_std::fields::fp::FpVar;
+use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError};
+
+const POSEIDON_FULL_ROUNDS: usize = 8;
+const POSEIDON_PARTIAL_ROUNDS: usize = 57;
+const POSEIDON_ALPHA: u64 = 5;
+const POSEIDON_RATE: usize = 2;
+const POSEIDON_CAPACITY: usize = 1;
+
+/// Returns the Poseidon configuration used for every commitment in this crate.
+///
+/// The round constants and MDS matrix are derived deterministically from the field size,
+/// so the native and in-circuit hashes agree for any `F`.
+pub fn poseidon_config<F: PrimeField>() -> PoseidonConfig<F> {
+    let (ark, mds) = find_poseidon_ark_and_mds::<F>(
+        F::MODULUS_BIT_SIZE as u64,
+        POSEIDON_RATE,
+        POSEIDON_FULL_ROUNDS as u64,
+        POSEIDON_PARTIAL_ROUNDS as u64,
+        0,
+    );
+    PoseidonConfig::new(
+        POSEIDON_FULL_ROUNDS,
+        POSEIDON_PARTIAL_ROUNDS,
+        POSEIDON_ALPHA,
+        mds,
+        ark,
+        POSEIDON_RATE,
+        POSEIDON_CAPACITY,
+    )
+}
+
+/// Hashes `elements` natively with the Poseidon sponge.
+pub fn hash<F: PrimeField + Absorb>(elements: &[F]) -> F {
+    let mut sponge = PoseidonSponge::new(&poseidon_config());
+    sponge.absorb(&elements);
+    sponge.squeeze_native_field_elements(1)[0]
+}
+
+/// Hashes `elements` inside the circuit, matching `hash`.
+pub fn hash_gadget<F: PrimeField + Absorb>(
+    cs: ConstraintSystemRef<F>,
+    elements: &[FpVar<F>],
+) -> Result<FpVar<F>, SynthesisError> {
+    let mut sponge = PoseidonSpongeVar::new(cs, &poseidon_config());
+    sponge.absorb(&elements)?;
+    Ok(sponge.squeeze_field_elements(1)?.remove(0))
+}
@@ -0,0 +1,133 @@
+use crate::{
+    circuit::DFACircuit,
+    errors::CompilerError,
+    regex::create_regex_and_dfa_from_str_and_defs,
+    structs::{DFAGraph, Disagreement, SubstringDefinitionsJson},
+};
+use ark_ff::PrimeField;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystem};
+use regex::bytes::Regex;
+
+/// Checks one regex against three independent oracles on every input.
+///
+/// # Arguments
+///
+/// * `regex_str` - The regex pattern, compiled with `create_regex_and_dfa_from_str_and_defs`.
+/// * `inputs` - The strings to test; they must not contain byte 0, which circuits treat as
+///   padding. Inputs longer than `max_len` are skipped, see `compare`.
+/// * `max_len` - The maximum input length of the synthesized circuits.
+///
+/// # Returns
+///
+/// A `Result` containing one `Disagreement` per input on which the oracles disagree, or a
+/// `CompilerError` if the regex does not compile.
+///
+/// # Function Behavior
+///
+/// - The oracles are a full match with `regex::bytes::Regex`, the native simulation
+///   `DFAGraph::run` and `ConstraintSystem::is_satisfied` of a `DFACircuit` over `F`.
+/// - Each failing input is shrunk with `shrink` before being reported, so the
+///   disagreement carries a minimal input that still shows it.
+pub fn check_regex<F: PrimeField>(
+    regex_str: &str,
+    inputs: &[&[u8]],
+    max_len: usize,
+) -> Result<Vec<Disagreement>, CompilerError> {
+    // The oracle wraps the pattern like the compiler does, so it cannot tell an unbalanced
+    // pattern from a valid one; reject those before wrapping
+    Regex::new(regex_str)?;
+    let dfa = create_regex_and_dfa_from_str_and_defs(
+        regex_str,
+        SubstringDefinitionsJson { transitions: vec![] },
+    )?
+    .dfa;
+    let regex = Regex::new(&format!("^(?:{})$", regex_str))?;
+
+    let mut disagreements = Vec::new();
+    for input in inputs {
+        if let Some(disagreement) = compare::<F>(regex_str, &regex, &dfa, input, max_len)? {
+            let minimal = shrink(&disagreement.input, |candidate| {
+                Ok(compare::<F>(regex_str, &regex, &dfa, candidate, max_len)?.is_some())
+            })?;
+            disagreements.push(compare::<F>(regex_str, &regex, &dfa, &minimal, max_len)?.unwrap());
+        }
+    }
+    Ok(disagreements)
+}
+
+/// Evaluates all three oracles on `input` and returns a `Disagreement` if they differ.
+///
+/// Inputs longer than `max_len` are not compared and return `Ok(None)`: the circuit cannot
+/// be synthesized for them at all, so it has no answer to disagree with.
+pub fn compare<F: PrimeField>(
+    regex_str: &str,
+    regex: &Regex,
+    dfa: &DFAGraph,
+    input: &[u8],
+    max_len: usize,
+) -> Result<Option<Disagreement>, CompilerError> {
+    if input.len() > max_len {
+        return Ok(None);
+    }
+    let regex_crate = regex.is_match(input);
+    let simulated = dfa.run(input).accepted;
+
+    let cs = ConstraintSystem::<F>::new_ref();
+    DFACircuit::<F>::from_bytes(dfa.clone(), input, max_len).generate_constraints(cs.clone())?;
+    let circuit = cs.is_satisfied()?;
+
+    if regex_crate == simulated && simulated == circuit {
+        return Ok(None);
+    }
+    Ok(Some(Disagreement {
+        regex: regex_str.to_string(),
+        input: input.to_vec(),
+        regex_crate,
+        simulated,
+        circuit,
+    }))
+}
+
+/// Shrinks `input` while `fails` keeps holding, returning a locally minimal failing input.
+///
+/// Repeatedly tries to remove a byte, then to replace a byte with a smaller one, until no
+/// single removal or replacement still fails.
+pub fn shrink<P>(input: &[u8], mut fails: P) -> Result<Vec<u8>, CompilerError>
+where
+    P: FnMut(&[u8]) -> Result<bool, CompilerError>,
+{
+    let mut current = input.to_vec();
+    loop {
+        let mut shrunk = false;
+
+        for i in 0..current.len() {
+            let mut candidate = current.clone();
+            candidate.remove(i);
+            if fails(&candidate)? {
+                current = candidate;
+                shrunk = true;
+                break;
+            }
+        }
+        if shrunk {
+            continue;
+        }
+
+        'replace: for i in 0..current.len() {
+            for smaller in [b'0', b'a', b'A'] {
+                if smaller < current[i] {
+                    let mut candidate = current.clone();
+                    candidate[i] = smaller;
+                    if fails(&candidate)? {
+                        current = candidate;
+                        shrunk = true;
+                        break 'replace;
+                    }
+                }
+            }
+        }
+        if !shrunk {
+            return Ok(current);
+        }
+    }
+}
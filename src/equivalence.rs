@@ -0,0 +1,89 @@
+use crate::structs::{Counterexample, DFAGraph};
+use std::collections::{BTreeSet, VecDeque};
+
+impl DFAGraph {
+    /// Checks that `self` and `other` accept the same strings.
+    ///
+    /// Returns a shortest counterexample, the lexicographically smallest among those, if
+    /// the languages differ. `self` is the left graph of the counterexample.
+    pub fn equivalent(&self, other: &DFAGraph) -> Result<(), Counterexample> {
+        match find_distinguishing_input(self, other, |left, right| left != right) {
+            Some(counterexample) => Err(counterexample),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks that every string accepted by `self` is also accepted by `other`.
+    ///
+    /// Returns a shortest string accepted by `self` but not by `other` otherwise.
+    pub fn is_subset_of(&self, other: &DFAGraph) -> Result<(), Counterexample> {
+        match find_distinguishing_input(self, other, |left, right| left && !right) {
+            Some(counterexample) => Err(counterexample),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Searches the product of `left` and `right` for a shortest input on which `differs` holds.
+///
+/// # Arguments
+///
+/// * `left` - The first graph, started in state 0.
+/// * `right` - The second graph, started in state 0.
+/// * `differs` - Decides from `(left accepts, right accepts)` whether a pair is a witness.
+///
+/// # Returns
+///
+/// The first witness found, or `None` if no reachable pair of states satisfies `differs`.
+///
+/// # Function Behavior
+///
+/// - Performs a Breadth-First Search over pairs of states, trying bytes in increasing
+///   order, so the first witness found is a shortest one.
+/// - A missing transition leads to a dead state, represented as `None`, that never accepts.
+/// - Byte 0 is skipped since circuits reserve it for padding; it is not part of any string.
+fn find_distinguishing_input<P>(
+    left: &DFAGraph,
+    right: &DFAGraph,
+    differs: P,
+) -> Option<Counterexample>
+where
+    P: Fn(bool, bool) -> bool,
+{
+    let accepts =
+        |dfa: &DFAGraph, state: Option<usize>| state.is_some_and(|s| dfa.is_accepting(s));
+    let step = |dfa: &DFAGraph, state: Option<usize>, byte: u8| {
+        state.and_then(|s| dfa.next_state(s, byte))
+    };
+
+    let start = (Some(0), Some(0));
+    let mut visited = BTreeSet::from([start]);
+    let mut queue = VecDeque::from([(start, Vec::new())]);
+
+    while let Some(((left_state, right_state), input)) = queue.pop_front() {
+        let accepted_by_left = accepts(left, left_state);
+        let accepted_by_right = accepts(right, right_state);
+        if differs(accepted_by_left, accepted_by_right) {
+            return Some(Counterexample {
+                input,
+                accepted_by_left,
+                accepted_by_right,
+            });
+        }
+
+        for byte in 1..=u8::MAX {
+            let next = (step(left, left_state, byte), step(right, right_state, byte));
+            // Both graphs are stuck for good; nothing reachable from here can differ
+            if next == (None, None) {
+                continue;
+            }
+            if visited.insert(next) {
+                let mut next_input = input.clone();
+                next_input.push(byte);
+                queue.push_back((next, next_input));
+            }
+        }
+    }
+
+    None
+}
@@ -12,7 +12,7 @@ pub enum CompilerError {
     BuildError {
         regex: String,
         #[source]
-        source: regex_automata::dfa::dense::BuildError,
+        source: Box<regex_automata::dfa::dense::BuildError>,
     },
     #[error("Error in Regex: {0}")]
     RegexError(#[from] regex::Error),
@@ -22,6 +22,18 @@ pub enum CompilerError {
     GenericError(String),
     #[error("Graph Error: {0}")]
     GraphError(String),
+    #[error("Synthesis Error: {0}")]
+    SynthesisError(#[from] ark_relations::r1cs::SynthesisError),
+    #[error("SNARK Error: {0}")]
+    SnarkError(String),
+    #[error("Unsupported curve: {0}")]
+    UnsupportedCurve(String),
+    #[error("snarkjs Error: {0}")]
+    SnarkjsError(String),
+    #[error("Code generation Error: {0}")]
+    CodegenError(String),
+    #[error("Input rejected: {0}")]
+    InputRejected(String),
     #[error("No accepted state found in DFA")]
     NoAcceptedState,
 }
@@ -0,0 +1,114 @@
+use crate::{
+    errors::CompilerError,
+    structs::{DFAGraph, RegexAndDFA},
+};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+
+impl DFAGraph {
+    /// Checks that the graph is well-formed enough to drive a circuit.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())`, or a `CompilerError::GraphError` describing the first problem found.
+    ///
+    /// # Function Behavior
+    ///
+    /// - Every `state_id` is unique and state 0, the start state, exists.
+    /// - Every `state_type` is either empty or `"accept"`.
+    /// - Every transition targets an existing state and takes at least one byte.
+    /// - No byte appears on two transitions leaving the same state, so the graph is
+    ///   deterministic.
+    /// - Byte 0 appears on no transition, since circuits reserve it for padding.
+    pub fn validate(&self) -> Result<(), CompilerError> {
+        let mut state_ids = BTreeSet::new();
+        for state in &self.states {
+            if !state_ids.insert(state.state_id) {
+                return Err(CompilerError::GraphError(format!(
+                    "Duplicate state {}",
+                    state.state_id
+                )));
+            }
+        }
+        if !state_ids.contains(&0) {
+            return Err(CompilerError::GraphError(
+                "Missing start state 0".to_string(),
+            ));
+        }
+
+        for state in &self.states {
+            if !matches!(state.state_type.as_str(), "" | "accept") {
+                return Err(CompilerError::GraphError(format!(
+                    "Unknown type \"{}\" of state {}",
+                    state.state_type, state.state_id
+                )));
+            }
+
+            let mut targets = BTreeMap::new();
+            for (&to_state, char_set) in &state.transitions {
+                if !state_ids.contains(&to_state) {
+                    return Err(CompilerError::GraphError(format!(
+                        "Transition from state {} targets unknown state {}",
+                        state.state_id, to_state
+                    )));
+                }
+                if char_set.is_empty() {
+                    return Err(CompilerError::GraphError(format!(
+                        "Transition from state {} to state {} takes no byte",
+                        state.state_id, to_state
+                    )));
+                }
+                for &byte in char_set {
+                    if byte == 0 {
+                        return Err(CompilerError::GraphError(format!(
+                            "Transition from state {} to state {} uses the padding byte 0",
+                            state.state_id, to_state
+                        )));
+                    }
+                    if let Some(other) = targets.insert(byte, to_state) {
+                        return Err(CompilerError::GraphError(format!(
+                            "Byte {} leads from state {} to both states {} and {}",
+                            byte, state.state_id, other, to_state
+                        )));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parses and validates a graph from JSON.
+    ///
+    /// Accepts either a serialized `DFAGraph` or a serialized `RegexAndDFA`, whose graph is
+    /// returned, so the output of `RegexAndDFA::to_json` can be read back directly.
+    pub fn from_json(json: &str) -> Result<Self, CompilerError> {
+        let mut document: Value = serde_json::from_str(json)?;
+        // A `RegexAndDFA` nests its graph under `dfa`
+        let dfa: DFAGraph = match document.get_mut("dfa") {
+            Some(graph) => serde_json::from_value(graph.take())?,
+            None => serde_json::from_value(document)?,
+        };
+        dfa.validate()?;
+        Ok(dfa)
+    }
+
+    /// Serializes the graph to pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, CompilerError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+impl RegexAndDFA {
+    /// Parses a `RegexAndDFA` from JSON and validates its graph.
+    pub fn from_json(json: &str) -> Result<Self, CompilerError> {
+        let regex_and_dfa: RegexAndDFA = serde_json::from_str(json)?;
+        regex_and_dfa.dfa.validate()?;
+        Ok(regex_and_dfa)
+    }
+
+    /// Serializes the regex, its graph and its substrings to pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, CompilerError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
@@ -0,0 +1,25 @@
+pub mod arkworks;
+pub mod backend;
+pub mod cardinality;
+pub mod chunked;
+pub mod circom;
+pub mod circuit;
+mod codegen;
+pub mod commitment;
+pub mod differential;
+pub mod equivalence;
+pub mod errors;
+pub mod interchange;
+pub mod noir;
+pub mod prover;
+pub mod r1cs;
+pub mod regex;
+pub mod sample;
+pub mod scaffold;
+pub mod simulate;
+pub mod snarkjs;
+pub mod step;
+pub mod structs;
+pub mod universal;
+pub mod visualize;
+pub mod zk_regex;
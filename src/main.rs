@@ -1,15 +1,327 @@
-use std::{fs::File, io::{self, Write}};
+use std::{
+    collections::BTreeSet,
+    fs::{self, File},
+    io::{self, Write},
+    path::PathBuf,
+    process,
+};
 
-use regex::create_regex_and_dfa_from_str_and_defs;
-use structs::SubstringDefinitionsJson;
-use arkworks::gen_arkworks_allstr;
+use ark_bls12_381::{Bls12_381, Fr as Bls12_381Fr};
+use ark_bn254::{Bn254, Fr as Bn254Fr};
+use ark_ec::pairing::Pairing;
+use ark_groth16::Groth16;
+use ark_ff::PrimeField;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystem};
+use arkworks_regex_circuit::{
+    arkworks::gen_arkworks_allstr,
+    backend::{backend_by_name, BackendOptions, InputEncoding, MatchMode},
+    cardinality::log2,
+    circuit::DFACircuit,
+    errors::CompilerError,
+    prover::{self, packed_public_inputs, public_inputs, Curve},
+    r1cs::{export_r1cs, export_wtns},
+    regex::create_regex_and_dfa_from_str_and_defs,
+    scaffold::{gen_crate, write_crate, CrateOptions},
+    structs::{DFAGraph, RegexAndDFA, SubstringDefinitionsJson},
+    visualize::GraphFormat,
+    zk_regex::import_zk_regex_json,
+};
+use clap::{Parser, Subcommand};
+use num_bigint::BigUint;
+use rand::{
+    rngs::{OsRng, StdRng},
+    SeedableRng,
+};
 
-mod structs;
-mod regex;
-mod errors;
-mod arkworks;
+const DEFAULT_MAX_LEN: usize = 256;
+
+#[derive(Parser)]
+#[command(about = "Compiles regular expressions into arkworks circuits")]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// Keep input bytes private and expose them packed into 31-byte public inputs.
+    #[arg(long, global = true)]
+    packed_input: bool,
+    /// Pairing curve whose scalar field the circuit is built over (bn254, bls12-381).
+    #[arg(long, global = true, default_value_t = Curve::Bn254)]
+    curve: Curve,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Synthesize a regex circuit and write it in iden3 `.r1cs` format, with an optional `.wtns`.
+    ExportR1cs {
+        /// Regex pattern to compile.
+        #[arg(long)]
+        regex: String,
+        /// String to assign to the circuit; writes the witness when given.
+        #[arg(long)]
+        input: Option<String>,
+        /// Maximum input length of the circuit.
+        #[arg(long, default_value_t = DEFAULT_MAX_LEN)]
+        max_len: usize,
+        /// Output path of the constraint system.
+        #[arg(long, default_value = "circuit.r1cs")]
+        r1cs: PathBuf,
+        /// Output path of the witness.
+        #[arg(long, default_value = "witness.wtns")]
+        wtns: PathBuf,
+    },
+    /// Run Groth16 setup, prove and verify for a regex and an input string.
+    Prove {
+        /// Regex pattern to compile.
+        #[arg(long)]
+        regex: String,
+        /// String to prove a match for.
+        #[arg(long)]
+        input: String,
+        /// Maximum input length of the circuit.
+        #[arg(long, default_value_t = DEFAULT_MAX_LEN)]
+        max_len: usize,
+    },
+    /// Check whether two regexes, or two DFA graphs saved as JSON, accept the same strings.
+    Compare {
+        /// Left regex pattern, or path to a JSON graph with `--graphs`.
+        left: String,
+        /// Right regex pattern, or path to a JSON graph with `--graphs`.
+        right: String,
+        /// Read both sides as JSON-serialized `DFAGraph`s instead of regexes.
+        #[arg(long)]
+        graphs: bool,
+    },
+    /// Compile a regex and write it, its DFA graph and its substrings as JSON.
+    Dump {
+        /// Regex pattern to compile.
+        #[arg(long)]
+        regex: String,
+        /// Output path; prints to stdout when omitted.
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Convert the JSON output of the zk-regex compiler into the JSON written by `dump`.
+    ImportZkRegex {
+        /// Path of the zk-regex JSON output.
+        input: PathBuf,
+        /// Output path; prints to stdout when omitted.
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Generate the circuit source of a regex, or of a DFA graph saved as JSON.
+    Generate {
+        /// Regex pattern to compile.
+        #[arg(long, required_unless_present = "graph", conflicts_with = "graph")]
+        regex: Option<String>,
+        /// JSON file holding a `DFAGraph`, or a `RegexAndDFA` written by `dump`.
+        #[arg(long)]
+        graph: Option<PathBuf>,
+        /// Code generator to use (arkworks, circom, noir).
+        #[arg(long, default_value = "arkworks")]
+        backend: String,
+        /// Name of the circuit struct or template; Noir derives its function names from it.
+        #[arg(long, default_value = "RegexCircuit")]
+        struct_name: String,
+        /// Also emit an entry point for inputs of this length.
+        #[arg(long)]
+        max_len: Option<usize>,
+        /// Whether the whole input or only a prefix of it has to match (full, prefix).
+        #[arg(long, default_value_t = MatchMode::Full)]
+        match_mode: MatchMode,
+        /// Transitions of a substring to reveal, as `from:to,from:to`; repeat per substring.
+        #[arg(long)]
+        reveal: Vec<String>,
+        /// Output path of the generated source; defaults to `generated_circuit` with the
+        /// extension of the backend.
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Write a standalone Cargo project proving matches of a regex with Groth16.
+    GenCrate {
+        /// Regex pattern to compile.
+        #[arg(long)]
+        regex: String,
+        /// Directory to write the project to.
+        #[arg(long)]
+        out: PathBuf,
+        /// Package name; defaults to the name of the output directory.
+        #[arg(long)]
+        name: Option<String>,
+        /// Maximum input length of the circuit.
+        #[arg(long, default_value_t = DEFAULT_MAX_LEN)]
+        max_len: usize,
+        /// Number of accepted and of rejected strings the generated tests check.
+        #[arg(long, default_value_t = 5)]
+        examples: usize,
+    },
+    /// Print strings a regex accepts and strings it rejects.
+    Examples {
+        /// Regex pattern to compile.
+        #[arg(long)]
+        regex: String,
+        /// Number of strings to print of each kind.
+        #[arg(long, default_value_t = 5)]
+        count: usize,
+        /// Maximum length of the strings.
+        #[arg(long, default_value_t = 32)]
+        max_len: usize,
+        /// Seed of the random samples; random when omitted.
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Count the strings a regex accepts, and how many remain once substrings are revealed.
+    Cardinality {
+        /// Regex pattern to compile.
+        #[arg(long)]
+        regex: String,
+        /// Maximum length of the strings.
+        #[arg(long, default_value_t = 32)]
+        max_len: usize,
+        /// Also print the number of accepted strings of every length.
+        #[arg(long)]
+        per_length: bool,
+        /// Transitions of a substring to reveal, as `from:to,from:to`; repeat per substring.
+        #[arg(long)]
+        reveal: Vec<String>,
+        /// Input whose revealed substrings to measure; random accepted strings when omitted.
+        #[arg(long)]
+        input: Option<String>,
+        /// Number of random accepted strings to measure without `--input`.
+        #[arg(long, default_value_t = 10)]
+        samples: usize,
+        /// Seed of the random samples; random when omitted.
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Render the DFA of a regex as a Graphviz DOT or Mermaid graph.
+    Graph {
+        /// Regex pattern to compile.
+        #[arg(long)]
+        regex: String,
+        /// Output format (dot, mermaid).
+        #[arg(long, default_value_t = GraphFormat::Dot)]
+        format: GraphFormat,
+        /// Transitions of a substring to highlight, as `from:to,from:to`; repeat per substring.
+        #[arg(long)]
+        reveal: Vec<String>,
+        /// Output path; prints to stdout when omitted.
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+}
 
 fn main() {
+    let cli = Cli::parse();
+
+    match cli.command {
+        None => generate_interactive(cli.packed_input),
+        Some(Command::ExportR1cs { regex, input, max_len, r1cs, wtns }) => {
+            let result = match cli.curve {
+                Curve::Bn254 => export_regex_r1cs::<Bn254Fr>(
+                    &regex, input.as_deref(), max_len, cli.packed_input, &r1cs, &wtns,
+                ),
+                Curve::Bls12_381 => export_regex_r1cs::<Bls12_381Fr>(
+                    &regex, input.as_deref(), max_len, cli.packed_input, &r1cs, &wtns,
+                ),
+            };
+            exit_on_error("Failed to export constraint system", result);
+        }
+        Some(Command::Prove { regex, input, max_len }) => {
+            let result = match cli.curve {
+                Curve::Bn254 => prove_regex::<Bn254>(&regex, &input, max_len, cli.packed_input),
+                Curve::Bls12_381 => {
+                    prove_regex::<Bls12_381>(&regex, &input, max_len, cli.packed_input)
+                }
+            };
+            match result {
+                Ok(is_valid) => {
+                    println!("Verification result: {}", is_valid);
+                    if !is_valid {
+                        process::exit(1);
+                    }
+                }
+                Err(e) => exit_on_error("Failed to prove", Err(e)),
+            }
+        }
+        Some(Command::Compare { left, right, graphs }) => {
+            exit_on_error("Failed to compare", compare(&left, &right, graphs));
+        }
+        Some(Command::Dump { regex, output }) => {
+            exit_on_error("Failed to dump regex", dump_regex(&regex, output.as_ref()));
+        }
+        Some(Command::ImportZkRegex { input, output }) => {
+            let result = import_zk_regex(&input, output.as_ref());
+            exit_on_error("Failed to import zk-regex output", result);
+        }
+        Some(Command::Generate {
+            regex, graph, backend, struct_name, max_len, match_mode, reveal, output,
+        }) => {
+            let result = parse_reveal(&reveal).and_then(|reveal| {
+                let options = BackendOptions {
+                    struct_name,
+                    max_len,
+                    match_mode,
+                    encoding: if cli.packed_input {
+                        InputEncoding::Packed
+                    } else {
+                        InputEncoding::Bytes
+                    },
+                    reveal,
+                };
+                generate_circuit(regex.as_deref(), graph.as_ref(), &backend, &options, output)
+            });
+            exit_on_error("Failed to generate circuit", result);
+        }
+        Some(Command::GenCrate { regex, out, name, max_len, examples }) => {
+            let options = CrateOptions {
+                name: name.unwrap_or_else(|| {
+                    out.file_name().map_or("regex_prover".into(), |n| n.to_string_lossy().into())
+                }),
+                max_len,
+                packed_input: cli.packed_input,
+                curve: cli.curve,
+                num_examples: examples,
+            };
+            exit_on_error("Failed to generate crate", gen_regex_crate(&regex, &out, &options));
+        }
+        Some(Command::Examples { regex, count, max_len, seed }) => {
+            let result = print_examples(&regex, count, max_len, seed);
+            exit_on_error("Failed to sample examples", result);
+        }
+        Some(Command::Cardinality {
+            regex, max_len, per_length, reveal, input, samples, seed,
+        }) => {
+            let result = parse_reveal(&reveal).and_then(|reveal| {
+                let inputs = match input {
+                    Some(input) => Some(vec![input.into_bytes()]),
+                    None if reveal.is_empty() => None,
+                    None => {
+                        let dfa = compile_dfa(&regex)?;
+                        let mut rng = match seed {
+                            Some(seed) => StdRng::seed_from_u64(seed),
+                            None => StdRng::from_entropy(),
+                        };
+                        Some(
+                            (0..samples)
+                                .filter_map(|_| dfa.sample_accepted(&mut rng, max_len))
+                                .collect(),
+                        )
+                    }
+                };
+                print_cardinality(&regex, max_len, per_length, &reveal, inputs.as_deref())
+            });
+            exit_on_error("Failed to count strings", result);
+        }
+        Some(Command::Graph { regex, format, reveal, output }) => {
+            let result = parse_reveal(&reveal).and_then(|reveal| {
+                let highlighted = reveal.into_iter().flatten().collect();
+                write_graph(&regex, format, &highlighted, output.as_ref())
+            });
+            exit_on_error("Failed to render graph", result);
+        }
+    }
+}
+
+fn generate_interactive(packed_input: bool) {
     // Example regex input
     // let regex_str = r"^[a-zA-Z]{2,}\s[a-zA-Z]{1,}'?-?[a-zA-Z]{2,}\s?([a-zA-Z]{1,})?$";
     // let regex_str = r"a[bc]+d";
@@ -24,7 +336,7 @@ fn main() {
     io::stdin().read_line(&mut regex_str).expect("Failed to read line");
     let regex_str = regex_str.trim(); // remove line break
 
-    // Placeholder SubstringDefinitionsJson; 
+    // Placeholder SubstringDefinitionsJson;
     let substr_defs_json = SubstringDefinitionsJson {
         transitions: vec![vec![(1, 2)]],
     };
@@ -35,15 +347,22 @@ fn main() {
         substr_defs_json,
     ) {
         Ok(regex_and_dfa) => {
-            println!("regex and dfa: {:#?}", regex_and_dfa);
+            println!("DFA graph:\n{}", regex_and_dfa.render(GraphFormat::Dot));
 
             // Generate Rust code for Arkworks circuit
-            let rust_code = gen_arkworks_allstr(
+            let rust_code = match gen_arkworks_allstr(
                 &regex_and_dfa.dfa,
                 "RegexCircuit",
                 regex_str,
-                regex_and_dfa.has_end_anchor,
-            );
+                None,
+                packed_input,
+            ) {
+                Ok(rust_code) => rust_code,
+                Err(e) => {
+                    eprintln!("Failed to generate circuit code: {}", e);
+                    return;
+                }
+            };
 
             // Save the generated code to a Rust file
             let mut file = File::create("generated_circuit.rs").expect("Unable to create file");
@@ -52,7 +371,320 @@ fn main() {
             println!("Generated Rust circuit code saved to 'generated_circuit.rs'");
         },
         Err(e) => {
-            println!("Failed to create RegexAndDFA: {}", e);
+            eprintln!("Failed to create RegexAndDFA: {}", e);
+        }
+    }
+}
+
+/// Reports the error of a subcommand on stderr, after `context`, and exits with status 1.
+fn exit_on_error(context: &str, result: Result<(), CompilerError>) {
+    if let Err(e) = result {
+        eprintln!("{}: {}", context, e);
+        process::exit(1);
+    }
+}
+
+/// Compiles `regex_str` into the `DFAGraph` driving the circuit.
+fn compile_dfa(regex_str: &str) -> Result<DFAGraph, CompilerError> {
+    Ok(create_regex_and_dfa_from_str_and_defs(
+        regex_str,
+        SubstringDefinitionsJson { transitions: vec![] },
+    )?
+    .dfa)
+}
+
+/// Loads a `DFAGraph` from a JSON file, or compiles it from a regex.
+fn load_dfa(spec: &str, is_graph: bool) -> Result<DFAGraph, CompilerError> {
+    if is_graph {
+        DFAGraph::from_json(&fs::read_to_string(spec)?)
+    } else {
+        compile_dfa(spec)
+    }
+}
+
+/// Prints whether `left` and `right` are equivalent, with a shortest counterexample if not.
+fn compare(left: &str, right: &str, graphs: bool) -> Result<(), CompilerError> {
+    let left_dfa = load_dfa(left, graphs)?;
+    let right_dfa = load_dfa(right, graphs)?;
+
+    let counterexample = match left_dfa.equivalent(&right_dfa) {
+        Ok(()) => {
+            println!("The languages are equivalent");
+            return Ok(());
+        }
+        Err(counterexample) => counterexample,
+    };
+    println!(
+        "The languages differ: \"{}\" is accepted by the {} side only",
+        counterexample.input.escape_ascii(),
+        if counterexample.accepted_by_left { "left" } else { "right" }
+    );
+    if left_dfa.is_subset_of(&right_dfa).is_ok() {
+        println!("The left language is a strict subset of the right one");
+    } else if right_dfa.is_subset_of(&left_dfa).is_ok() {
+        println!("The right language is a strict subset of the left one");
+    }
+    Ok(())
+}
+
+/// Writes the `RegexAndDFA` of `regex_str` as JSON to `output`, or to stdout.
+fn dump_regex(regex_str: &str, output: Option<&PathBuf>) -> Result<(), CompilerError> {
+    let regex_and_dfa = create_regex_and_dfa_from_str_and_defs(
+        regex_str,
+        SubstringDefinitionsJson { transitions: vec![] },
+    )?;
+    write_regex_and_dfa(&regex_and_dfa, output)
+}
+
+/// Converts the zk-regex output at `input` and writes it as JSON to `output`, or to stdout.
+fn import_zk_regex(input: &PathBuf, output: Option<&PathBuf>) -> Result<(), CompilerError> {
+    let regex_and_dfa = import_zk_regex_json(&fs::read_to_string(input)?)?;
+    write_regex_and_dfa(&regex_and_dfa, output)
+}
+
+fn write_regex_and_dfa(
+    regex_and_dfa: &RegexAndDFA,
+    output: Option<&PathBuf>,
+) -> Result<(), CompilerError> {
+    let json = regex_and_dfa.to_json()?;
+    match output {
+        Some(path) => {
+            fs::write(path, json)?;
+            println!("Regex and DFA saved to '{}'", path.display());
+        }
+        None => println!("{}", json),
+    }
+    Ok(())
+}
+
+/// Parses `--reveal` values, one `from:to,from:to` list of transitions per substring.
+fn parse_reveal(reveal: &[String]) -> Result<Vec<BTreeSet<(usize, usize)>>, CompilerError> {
+    let parse_state = |s: &str| {
+        s.trim()
+            .parse::<usize>()
+            .map_err(|_| CompilerError::ParseError(format!("Invalid state \"{}\"", s)))
+    };
+    reveal
+        .iter()
+        .map(|transitions| {
+            transitions
+                .split(',')
+                .map(|transition| {
+                    let (from_state, to_state) = transition.split_once(':').ok_or_else(|| {
+                        CompilerError::ParseError(format!(
+                            "Invalid transition \"{}\", expected from:to",
+                            transition
+                        ))
+                    })?;
+                    Ok((parse_state(from_state)?, parse_state(to_state)?))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Generates the circuit source of `regex_str`, or of the graph stored at `graph_path`.
+fn generate_circuit(
+    regex_str: Option<&str>,
+    graph_path: Option<&PathBuf>,
+    backend: &str,
+    options: &BackendOptions,
+    output: Option<PathBuf>,
+) -> Result<(), CompilerError> {
+    let backend = backend_by_name(backend)?;
+    let (dfa, source) = match (regex_str, graph_path) {
+        (Some(regex_str), _) => (compile_dfa(regex_str)?, regex_str.to_string()),
+        (None, Some(path)) => {
+            let dfa = DFAGraph::from_json(&fs::read_to_string(path)?)?;
+            (dfa, format!("DFA graph loaded from {}", path.display()))
+        }
+        (None, None) => {
+            return Err(CompilerError::GenericError(
+                "Either a regex or a graph is required".to_string(),
+            ))
+        }
+    };
+
+    let code = backend.generate(&dfa, &source, options)?;
+    let output = output.unwrap_or_else(|| {
+        PathBuf::from(format!("generated_circuit.{}", backend.file_extension()))
+    });
+    fs::write(&output, code)?;
+    println!("Generated {} circuit code saved to '{}'", backend.name(), output.display());
+    Ok(())
+}
+
+/// Writes the prover project of `regex_str` to `out`.
+fn gen_regex_crate(regex_str: &str, out: &PathBuf, options: &CrateOptions) -> Result<(), CompilerError> {
+    let files = gen_crate(&compile_dfa(regex_str)?, regex_str, options)?;
+    write_crate(out, &files)?;
+    println!("Crate '{}' saved to '{}'", options.name, out.display());
+    println!("Run `cargo run --release -- setup`, then `prove <input>` and `verify <input>` in it");
+    Ok(())
+}
+
+/// Prints the shortest strings `regex_str` accepts, then random accepted and rejected ones.
+fn print_examples(
+    regex_str: &str,
+    count: usize,
+    max_len: usize,
+    seed: Option<u64>,
+) -> Result<(), CompilerError> {
+    let dfa = compile_dfa(regex_str)?;
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let print = |title: &str, inputs: Vec<Vec<u8>>| {
+        println!("{}:", title);
+        if inputs.is_empty() {
+            println!("  (none of at most {} bytes)", max_len);
+        }
+        for input in inputs {
+            println!("  \"{}\"", input.escape_ascii());
+        }
+    };
+
+    print("Shortest accepted", dfa.shortest_accepted(count, max_len));
+    let accepted = (0..count).filter_map(|_| dfa.sample_accepted(&mut rng, max_len)).collect();
+    print("Random accepted", accepted);
+    let rejected = (0..count).filter_map(|_| dfa.sample_rejected(&mut rng, max_len)).collect();
+    print("Random rejected", rejected);
+    Ok(())
+}
+
+/// Prints how many strings of at most `max_len` bytes `regex_str` accepts, then how many of
+/// them reveal the same substrings as each of `inputs`.
+fn print_cardinality(
+    regex_str: &str,
+    max_len: usize,
+    per_length: bool,
+    reveal: &[BTreeSet<(usize, usize)>],
+    inputs: Option<&[Vec<u8>]>,
+) -> Result<(), CompilerError> {
+    let dfa = compile_dfa(regex_str)?;
+    let size = dfa.count_accepted(max_len);
+    if per_length {
+        for (len, count) in size.counts.iter().enumerate() {
+            println!("  length {:>3}: {}", len, format_count(count));
+        }
+    }
+    println!(
+        "Accepted strings of at most {} bytes: {} ({:.2} bits)",
+        max_len,
+        format_count(&size.total()),
+        size.log2_total()
+    );
+
+    for input in inputs.unwrap_or_default() {
+        let leakage = dfa.leakage(input, max_len, reveal)?;
+        println!(
+            "\"{}\": {} consistent strings, {:.2} bits left, {:.2} bits disclosed",
+            input.escape_ascii(),
+            format_count(&leakage.consistent),
+            leakage.remaining_bits(),
+            leakage.disclosed_bits()
+        );
+        if leakage.discloses_input() {
+            println!("  warning: the revealed substrings disclose the whole input");
+        }
+    }
+    Ok(())
+}
+
+/// Formats `count` in full, or as a power of two when it has more than 20 digits.
+fn format_count(count: &BigUint) -> String {
+    let digits = count.to_string();
+    if digits.len() > 20 {
+        format!("~2^{:.2}", log2(count))
+    } else {
+        digits
+    }
+}
+
+/// Renders the DFA of `regex_str` in `format` to `output`, or to stdout, drawing the
+/// `highlighted` transitions in red.
+fn write_graph(
+    regex_str: &str,
+    format: GraphFormat,
+    highlighted: &BTreeSet<(usize, usize)>,
+    output: Option<&PathBuf>,
+) -> Result<(), CompilerError> {
+    let graph = compile_dfa(regex_str)?.render(format, highlighted);
+    match output {
+        Some(path) => {
+            File::create(path)?.write_all(graph.as_bytes())?;
+            println!("{} graph saved to '{}'", format, path.display());
+        }
+        None => println!("{}", graph),
+    }
+    Ok(())
+}
+
+/// Synthesizes the circuit of `regex_str` over `F` and writes it as `.r1cs` (and `.wtns`).
+fn export_regex_r1cs<F: PrimeField>(
+    regex_str: &str,
+    input: Option<&str>,
+    max_len: usize,
+    packed_input: bool,
+    r1cs_path: &PathBuf,
+    wtns_path: &PathBuf,
+) -> Result<(), CompilerError> {
+    let mut circuit = DFACircuit::<F>::from_bytes(
+        compile_dfa(regex_str)?,
+        input.unwrap_or("").as_bytes(),
+        max_len,
+    );
+    circuit.packed_input = packed_input;
+    let circuit_check = circuit.check_input();
+
+    let cs = ConstraintSystem::<F>::new_ref();
+    circuit.generate_constraints(cs.clone())?;
+
+    export_r1cs(&cs, r1cs_path)?;
+    println!(
+        "Constraint system ({} constraints) saved to '{}'",
+        cs.num_constraints(),
+        r1cs_path.display()
+    );
+
+    if input.is_some() {
+        if let Err(e) = circuit_check {
+            println!("Warning: {}; the witness is not satisfying", e);
         }
+        export_wtns(&cs, wtns_path)?;
+        println!("Witness saved to '{}'", wtns_path.display());
     }
+
+    Ok(())
+}
+
+/// Runs setup, prove and verify for the circuit of `regex_str` over the pairing `E`.
+fn prove_regex<E: Pairing>(
+    regex_str: &str,
+    input: &str,
+    max_len: usize,
+    packed_input: bool,
+) -> Result<bool, CompilerError> {
+    let mut circuit = DFACircuit::<E::ScalarField>::from_bytes(
+        compile_dfa(regex_str)?,
+        input.as_bytes(),
+        max_len,
+    );
+    circuit.packed_input = packed_input;
+    circuit.check_input()?;
+    let mut rng = OsRng;
+
+    let (pk, vk) = prover::setup::<_, Groth16<E>, _, _>(circuit.clone(), &mut rng)?;
+    println!("[+] Proving and Verifying keys have been generated");
+
+    let proof = prover::prove::<_, Groth16<E>, _, _>(&pk, circuit, &mut rng)?;
+    println!("[+] Proof has been generated");
+
+    let public = if packed_input {
+        packed_public_inputs(input, max_len)
+    } else {
+        public_inputs(input, max_len)
+    };
+    prover::verify::<_, Groth16<E>>(&vk, &public, &proof)
 }
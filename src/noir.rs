@@ -0,0 +1,200 @@
+use crate::{codegen::put_space, structs::DFAGraph};
+use std::collections::BTreeSet;
+
+/// Generates Noir functions matching `dfa_graph`, with the semantics of the arkworks circuit.
+///
+/// # Arguments
+///
+/// * `dfa_graph` - The graph to match.
+/// * `name` - The prefix of the emitted public functions.
+/// * `regex_str` - The regex, recorded in a comment.
+/// * `substring_ranges` - One set of `(from, to)` transitions per substring to reveal.
+///
+/// # Returns
+///
+/// The source of `{name}_match<let N: u32>(input: [u8; N]) -> bool` and, when there are
+/// substrings, `{name}_reveal<let N: u32>(input: [u8; N]) -> [[u8; N]; K]`.
+///
+/// # Function Behavior
+///
+/// - `transition` maps a state and a byte to the next state, or to the dead state
+///   `NUM_STATES` when there is no transition.
+/// - Byte 0 is padding: it keeps the state and may only be followed by more padding.
+/// - `{name}_reveal` keeps `input[i]` in row `j` when byte `i` takes a transition of
+///   substring `j`, and 0 elsewhere; it is only meaningful for inputs `{name}_match` accepts.
+pub fn gen_noir_allstr(
+    dfa_graph: &DFAGraph,
+    name: &str,
+    regex_str: &str,
+    substring_ranges: &[BTreeSet<(usize, usize)>],
+) -> String {
+    let mut final_code = [
+        generate_declarations_noir(dfa_graph, regex_str),
+        generate_transition_logic_noir(dfa_graph),
+        generate_accept_logic_noir(dfa_graph),
+        generate_match_function_noir(name),
+    ]
+    .concat();
+    if !substring_ranges.is_empty() {
+        final_code.extend(generate_reveal_function_noir(name, substring_ranges));
+    }
+
+    final_code.join("\n")
+}
+
+fn generate_declarations_noir(dfa_graph: &DFAGraph, regex_str: &str) -> Vec<String> {
+    vec![
+        format!("// Regex: {}", regex_str.replace('\n', "\\n").replace('\r', "\\r")),
+        "".to_string(),
+        "// States are numbered by their state_id; NUM_STATES is the dead state".to_string(),
+        format!(
+            "global NUM_STATES: u32 = {};",
+            dfa_graph.states.iter().map(|s| s.state_id + 1).max().unwrap_or(0)
+        ),
+        "".to_string(),
+    ]
+}
+
+fn generate_transition_logic_noir(dfa_graph: &DFAGraph) -> Vec<String> {
+    let mut lines = vec![
+        "fn transition(state: u32, byte: u8) -> u32 {".to_string(),
+        format!("{}let mut next = NUM_STATES;", put_space(1)),
+    ];
+
+    for state in &dfa_graph.states {
+        for (&to_state, char_set) in &state.transitions {
+            lines.push(format!(
+                "{}if (state == {}) & {} {{",
+                put_space(1),
+                state.state_id,
+                byte_condition(char_set)
+            ));
+            lines.push(format!("{}next = {};", put_space(2), to_state));
+            lines.push(format!("{}}}", put_space(1)));
+        }
+    }
+
+    lines.push(format!("{}next", put_space(1)));
+    lines.push("}".to_string());
+    lines.push("".to_string());
+    lines
+}
+
+/// Builds the parenthesized condition that `byte` is in `char_set`, testing runs as ranges.
+fn byte_condition(char_set: &BTreeSet<u8>) -> String {
+    let mut runs: Vec<(u8, u8)> = vec![];
+    for &byte in char_set {
+        match runs.last_mut() {
+            Some((_, end)) if *end as u16 + 1 == byte as u16 => *end = byte,
+            _ => runs.push((byte, byte)),
+        }
+    }
+
+    if runs.is_empty() {
+        return "false".to_string();
+    }
+    let terms = runs
+        .iter()
+        .map(|&(start, end)| {
+            if start == end {
+                format!("(byte == {})", start)
+            } else {
+                format!("((byte >= {}) & (byte <= {}))", start, end)
+            }
+        })
+        .collect::<Vec<_>>();
+    if terms.len() > 1 {
+        format!("({})", terms.join(" | "))
+    } else {
+        terms[0].clone()
+    }
+}
+
+fn generate_accept_logic_noir(dfa_graph: &DFAGraph) -> Vec<String> {
+    let accept_states = dfa_graph
+        .states
+        .iter()
+        .filter(|s| s.state_type == "accept")
+        .map(|s| format!("(state == {})", s.state_id))
+        .collect::<Vec<_>>();
+    let condition = if accept_states.is_empty() {
+        "false".to_string()
+    } else {
+        accept_states.join(" | ")
+    };
+
+    vec![
+        "fn is_accepting(state: u32) -> bool {".to_string(),
+        format!("{}{}", put_space(1), condition),
+        "}".to_string(),
+        "".to_string(),
+    ]
+}
+
+fn generate_match_function_noir(name: &str) -> Vec<String> {
+    vec![
+        format!("pub fn {}_match<let N: u32>(input: [u8; N]) -> bool {{", name),
+        format!("{}let mut state = 0;", put_space(1)),
+        format!("{}let mut valid = true;", put_space(1)),
+        format!("{}let mut padding_started = false;", put_space(1)),
+        format!("{}for i in 0..N {{", put_space(1)),
+        format!("{}let byte = input[i];", put_space(2)),
+        format!("{}if byte == 0 {{", put_space(2)),
+        format!("{}padding_started = true;", put_space(3)),
+        format!("{}}} else {{", put_space(2)),
+        format!("{}// Padding may only be followed by more padding", put_space(3)),
+        format!("{}valid = valid & !padding_started;", put_space(3)),
+        format!("{}state = transition(state, byte);", put_space(3)),
+        format!("{}}}", put_space(2)),
+        format!("{}}}", put_space(1)),
+        format!("{}valid & is_accepting(state)", put_space(1)),
+        "}".to_string(),
+        "".to_string(),
+    ]
+}
+
+fn generate_reveal_function_noir(
+    name: &str,
+    substring_ranges: &[BTreeSet<(usize, usize)>],
+) -> Vec<String> {
+    let num_substrings = substring_ranges.len();
+    let mut lines = vec![
+        format!(
+            "pub fn {}_reveal<let N: u32>(input: [u8; N]) -> [[u8; N]; {}] {{",
+            name, num_substrings
+        ),
+        format!("{}let mut reveal = [[0; N]; {}];", put_space(1), num_substrings),
+        format!("{}let mut state = 0;", put_space(1)),
+        format!("{}for i in 0..N {{", put_space(1)),
+        format!("{}let byte = input[i];", put_space(2)),
+        format!("{}if byte != 0 {{", put_space(2)),
+        format!("{}let next = transition(state, byte);", put_space(3)),
+    ];
+
+    for (j, ranges) in substring_ranges.iter().enumerate() {
+        let condition = if ranges.is_empty() {
+            "false".to_string()
+        } else {
+            ranges
+                .iter()
+                .map(|(from_state, to_state)| {
+                    format!("((state == {}) & (next == {}))", from_state, to_state)
+                })
+                .collect::<Vec<_>>()
+                .join(" | ")
+        };
+        lines.push(format!("{}if {} {{", put_space(3), condition));
+        lines.push(format!("{}reveal[{}][i] = byte;", put_space(4), j));
+        lines.push(format!("{}}}", put_space(3)));
+    }
+
+    lines.extend([
+        format!("{}state = next;", put_space(3)),
+        format!("{}}}", put_space(2)),
+        format!("{}}}", put_space(1)),
+        format!("{}reveal", put_space(1)),
+        "}".to_string(),
+        "".to_string(),
+    ]);
+    lines
+}
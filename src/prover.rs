@@ -0,0 +1,173 @@
+use crate::{
+    circuit::{DFACircuit, PACKED_CHUNK_BYTES},
+    errors::CompilerError,
+    structs::DFATable,
+    universal::{table_chunks, table_commitment, TableMode},
+};
+use ark_crypto_primitives::sponge::Absorb;
+use ark_ff::PrimeField;
+use ark_relations::r1cs::ConstraintSynthesizer;
+use ark_snark::SNARK;
+use ark_std::rand::{CryptoRng, RngCore};
+use std::{fmt, str::FromStr};
+
+/// Pairing-friendly curves the prover layer can be instantiated with.
+///
+/// MNT4/MNT6 cycles for recursion are not implemented yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Curve {
+    Bn254,
+    Bls12_381,
+}
+
+impl Curve {
+    /// Every supported curve, in the order they are listed by the CLI.
+    pub const ALL: [Curve; 2] = [Curve::Bn254, Curve::Bls12_381];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Curve::Bn254 => "bn254",
+            Curve::Bls12_381 => "bls12-381",
+        }
+    }
+}
+
+impl fmt::Display for Curve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Curve {
+    type Err = CompilerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Curve::ALL
+            .into_iter()
+            .find(|curve| curve.name() == s.to_ascii_lowercase())
+            .ok_or_else(|| {
+                let supported: Vec<&str> = Curve::ALL.iter().map(Curve::name).collect();
+                CompilerError::UnsupportedCurve(format!(
+                    "\"{}\", expected one of: {}",
+                    s,
+                    supported.join(", ")
+                ))
+            })
+    }
+}
+
+/// Runs the circuit-specific setup of the proof system `S` for `circuit`.
+///
+/// For Groth16 over a pairing `E`, use `S = Groth16<E>`. Universal-setup proof systems such as
+/// Marlin are not supported: `ark-marlin` has no release built on arkworks 0.4.
+pub fn setup<F, S, C, R>(
+    circuit: C,
+    rng: &mut R,
+) -> Result<(S::ProvingKey, S::VerifyingKey), CompilerError>
+where
+    F: PrimeField,
+    S: SNARK<F>,
+    C: ConstraintSynthesizer<F>,
+    R: RngCore + CryptoRng,
+{
+    S::circuit_specific_setup(circuit, rng).map_err(snark_error)
+}
+
+/// Proves that `circuit` is satisfied using the proving key `pk` of the proof system `S`.
+pub fn prove<F, S, C, R>(
+    pk: &S::ProvingKey,
+    circuit: C,
+    rng: &mut R,
+) -> Result<S::Proof, CompilerError>
+where
+    F: PrimeField,
+    S: SNARK<F>,
+    C: ConstraintSynthesizer<F>,
+    R: RngCore + CryptoRng,
+{
+    S::prove(pk, circuit, rng).map_err(snark_error)
+}
+
+/// Proves a `DFACircuit` after checking natively that its input matches the regex.
+///
+/// A non-matching input is rejected with `CompilerError::InputRejected`, naming the failing
+/// byte offset, instead of producing a proof that does not verify.
+pub fn prove_checked<F, S, R>(
+    pk: &S::ProvingKey,
+    circuit: DFACircuit<F>,
+    rng: &mut R,
+) -> Result<S::Proof, CompilerError>
+where
+    F: PrimeField,
+    S: SNARK<F>,
+    R: RngCore + CryptoRng,
+{
+    circuit.check_input()?;
+    prove::<F, S, _, _>(pk, circuit, rng)
+}
+
+/// Verifies `proof` against `public_inputs` using the verifying key `vk` of the proof system `S`.
+pub fn verify<F: PrimeField, S: SNARK<F>>(
+    vk: &S::VerifyingKey,
+    public_inputs: &[F],
+    proof: &S::Proof,
+) -> Result<bool, CompilerError> {
+    S::verify(vk, public_inputs, proof).map_err(snark_error)
+}
+
+fn snark_error<E: std::fmt::Display>(e: E) -> CompilerError {
+    CompilerError::SnarkError(e.to_string())
+}
+
+/// Encodes `input` as the circuit's `input` field, one field element per byte.
+pub fn encode_input<F: PrimeField>(input: &str) -> Vec<F> {
+    input.bytes().map(|b| F::from(b as u64)).collect()
+}
+
+/// Builds the public inputs of a circuit whose bytes are public inputs.
+///
+/// Each byte becomes one field element and the result is zero-padded to `max_len`.
+pub fn public_inputs<F: PrimeField>(input: &str, max_len: usize) -> Vec<F> {
+    let mut inputs = encode_input(input);
+    inputs.resize(max_len, F::zero());
+    inputs
+}
+
+/// Builds the public inputs of a circuit generated with packed input.
+///
+/// The input is zero-padded to `max_len` bytes and split into 31-byte chunks, each read
+/// as a little-endian integer, matching the packing constraints of the circuit.
+pub fn packed_public_inputs<F: PrimeField>(input: &str, max_len: usize) -> Vec<F> {
+    let mut bytes = input.as_bytes().to_vec();
+    bytes.resize(max_len, 0);
+    bytes
+        .chunks(PACKED_CHUNK_BYTES)
+        .map(F::from_le_bytes_mod_order)
+        .collect()
+}
+
+/// Builds the public inputs of a `UniversalRegexCircuit`.
+///
+/// The input's public inputs (packed or not) come first, followed by the packed table
+/// chunks in `TableMode::Public` or the table commitment under `table_salt` in
+/// `TableMode::Committed`. Circuits with `private_input` expose commitments instead; see
+/// `UniversalRegexCircuit::hidden`.
+pub fn universal_public_inputs<F: PrimeField + Absorb>(
+    table: &DFATable,
+    table_mode: TableMode,
+    table_salt: F,
+    input: &str,
+    max_len: usize,
+    packed_input: bool,
+) -> Vec<F> {
+    let mut inputs = if packed_input {
+        packed_public_inputs(input, max_len)
+    } else {
+        public_inputs(input, max_len)
+    };
+    match table_mode {
+        TableMode::Public => inputs.extend(table_chunks::<F>(table)),
+        TableMode::Committed => inputs.push(table_commitment::<F>(table, table_salt)),
+    }
+    inputs
+}
@@ -0,0 +1,178 @@
+use crate::errors::CompilerError;
+use ark_ff::{BigInteger, PrimeField};
+use ark_relations::r1cs::{ConstraintMatrices, ConstraintSystemRef};
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+const R1CS_MAGIC: &[u8; 4] = b"r1cs";
+const R1CS_VERSION: u32 = 1;
+const WTNS_MAGIC: &[u8; 4] = b"wtns";
+const WTNS_VERSION: u32 = 2;
+
+/// Number of bytes used by iden3 files to store one element of `F`.
+fn field_size<F: PrimeField>() -> usize {
+    (F::MODULUS_BIT_SIZE as usize).div_ceil(64) * 8
+}
+
+/// Encodes `value` as a little-endian integer of exactly `field_size::<F>()` bytes.
+fn field_to_bytes<F: PrimeField>(value: &F) -> Vec<u8> {
+    let mut bytes = value.into_bigint().to_bytes_le();
+    bytes.resize(field_size::<F>(), 0);
+    bytes
+}
+
+fn modulus_to_bytes<F: PrimeField>() -> Vec<u8> {
+    let mut bytes = F::MODULUS.to_bytes_le();
+    bytes.resize(field_size::<F>(), 0);
+    bytes
+}
+
+fn u32_from_usize(value: usize, what: &str) -> Result<u32, CompilerError> {
+    u32::try_from(value)
+        .map_err(|_| CompilerError::GenericError(format!("Too many {} for iden3 format", what)))
+}
+
+/// Writes a section with its `(type, size)` header.
+fn write_section<W: Write>(writer: &mut W, typ: u32, body: &[u8]) -> Result<(), CompilerError> {
+    writer.write_all(&typ.to_le_bytes())?;
+    writer.write_all(&(body.len() as u64).to_le_bytes())?;
+    writer.write_all(body)?;
+    Ok(())
+}
+
+/// Encodes one side (A, B or C) of a constraint as `nnz` followed by `(wire, coeff)` pairs.
+fn encode_linear_combination<F: PrimeField>(
+    body: &mut Vec<u8>,
+    row: &[(F, usize)],
+) -> Result<(), CompilerError> {
+    body.extend(u32_from_usize(row.len(), "terms")?.to_le_bytes());
+    for (coeff, wire) in row {
+        body.extend(u32_from_usize(*wire, "wires")?.to_le_bytes());
+        body.extend(field_to_bytes(coeff));
+    }
+    Ok(())
+}
+
+/// Writes the constraint matrices of a synthesized circuit in iden3's binary `.r1cs` format.
+///
+/// # Arguments
+///
+/// * `matrices` - The matrices of a finalized `ConstraintSystem`.
+/// * `writer` - The destination of the encoded file.
+///
+/// # Returns
+///
+/// A `Result` containing `()` if writing succeeds, or a `CompilerError` if it fails.
+///
+/// # Function Behavior
+///
+/// - Maps arkworks variables onto wires the way circom does: wire 0 is the constant one,
+///   followed by the public inputs and then every witness variable.
+/// - Declares all arkworks instance variables as public inputs (no public outputs), and no
+///   private inputs, since arkworks does not distinguish them from internal witnesses.
+/// - Writes the header, constraint and wire-to-label sections, labelling each wire by its index.
+pub fn write_r1cs<F: PrimeField, W: Write>(
+    matrices: &ConstraintMatrices<F>,
+    mut writer: W,
+) -> Result<(), CompilerError> {
+    let n_wires = matrices.num_instance_variables + matrices.num_witness_variables;
+    let n_pub_in = matrices.num_instance_variables - 1;
+
+    let mut header = Vec::new();
+    header.extend((field_size::<F>() as u32).to_le_bytes());
+    header.extend(modulus_to_bytes::<F>());
+    header.extend(u32_from_usize(n_wires, "wires")?.to_le_bytes());
+    header.extend(0u32.to_le_bytes()); // nPubOut
+    header.extend(u32_from_usize(n_pub_in, "public inputs")?.to_le_bytes());
+    header.extend(0u32.to_le_bytes()); // nPrvIn
+    header.extend((n_wires as u64).to_le_bytes()); // nLabels
+    header.extend(u32_from_usize(matrices.num_constraints, "constraints")?.to_le_bytes());
+
+    let mut constraints = Vec::new();
+    for i in 0..matrices.num_constraints {
+        encode_linear_combination(&mut constraints, &matrices.a[i])?;
+        encode_linear_combination(&mut constraints, &matrices.b[i])?;
+        encode_linear_combination(&mut constraints, &matrices.c[i])?;
+    }
+
+    let labels: Vec<u8> = (0..n_wires as u64).flat_map(|label| label.to_le_bytes()).collect();
+
+    writer.write_all(R1CS_MAGIC)?;
+    writer.write_all(&R1CS_VERSION.to_le_bytes())?;
+    writer.write_all(&3u32.to_le_bytes())?;
+    write_section(&mut writer, 1, &header)?;
+    write_section(&mut writer, 2, &constraints)?;
+    write_section(&mut writer, 3, &labels)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Writes a full assignment in iden3's binary `.wtns` format.
+///
+/// # Arguments
+///
+/// * `witness` - Every wire value, in the wire order used by `write_r1cs`.
+/// * `writer` - The destination of the encoded file.
+///
+/// # Returns
+///
+/// A `Result` containing `()` if writing succeeds, or a `CompilerError` if it fails.
+pub fn write_wtns<F: PrimeField, W: Write>(witness: &[F], mut writer: W) -> Result<(), CompilerError> {
+    let mut header = Vec::new();
+    header.extend((field_size::<F>() as u32).to_le_bytes());
+    header.extend(modulus_to_bytes::<F>());
+    header.extend(u32_from_usize(witness.len(), "witness values")?.to_le_bytes());
+
+    let values: Vec<u8> = witness.iter().flat_map(field_to_bytes).collect();
+
+    writer.write_all(WTNS_MAGIC)?;
+    writer.write_all(&WTNS_VERSION.to_le_bytes())?;
+    writer.write_all(&2u32.to_le_bytes())?;
+    write_section(&mut writer, 1, &header)?;
+    write_section(&mut writer, 2, &values)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Returns the full assignment of a synthesized constraint system in wire order.
+///
+/// The constant one and public inputs come first, followed by the witness variables.
+pub fn full_assignment<F: PrimeField>(cs: &ConstraintSystemRef<F>) -> Option<Vec<F>> {
+    let cs = cs.borrow()?;
+    if cs.is_in_setup_mode() {
+        return None;
+    }
+    Some(
+        cs.instance_assignment
+            .iter()
+            .chain(cs.witness_assignment.iter())
+            .cloned()
+            .collect(),
+    )
+}
+
+/// Finalizes `cs` and writes its matrices to `path` as a `.r1cs` file.
+pub fn export_r1cs<F: PrimeField, P: AsRef<Path>>(
+    cs: &ConstraintSystemRef<F>,
+    path: P,
+) -> Result<(), CompilerError> {
+    cs.finalize();
+    let matrices = cs.to_matrices().ok_or_else(|| {
+        CompilerError::GenericError("Constraint system did not construct matrices".to_string())
+    })?;
+    write_r1cs(&matrices, BufWriter::new(File::create(path)?))
+}
+
+/// Writes the full assignment of `cs` to `path` as a `.wtns` file.
+pub fn export_wtns<F: PrimeField, P: AsRef<Path>>(
+    cs: &ConstraintSystemRef<F>,
+    path: P,
+) -> Result<(), CompilerError> {
+    let witness = full_assignment(cs).ok_or_else(|| {
+        CompilerError::GenericError("Constraint system has no assignment".to_string())
+    })?;
+    write_wtns(&witness, BufWriter::new(File::create(path)?))
+}
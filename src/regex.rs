@@ -107,7 +107,7 @@ fn parse_transition(state: &mut DFAStateInfo, transition: &str) -> Result<(), Co
 /// Removes EOI transitions and marks their source states as accept states.
 fn handle_eoi_transitions(dfa_info: &mut DFAGraphInfo) {
     for state in &mut dfa_info.states {
-        if let Some(_) = state.edges.get("EOI") {
+        if state.edges.contains_key("EOI") {
             state.typ = String::from("accept");
             state.edges.remove("EOI");
         }
@@ -204,6 +204,7 @@ fn create_special_char_mappings() -> BTreeMap<&'static str, u8> {
         ("\\\"", 34),
         ("\\'", 39),
         ("\\", 92),
+        ("\\\\", 92),
         ("' '", 32),
     ]
     .iter()
@@ -246,7 +247,7 @@ fn process_range_edge(
 
     edges
         .entry(value)
-        .or_insert_with(BTreeSet::new)
+        .or_default()
         .extend(char_range);
     Ok(())
 }
@@ -277,7 +278,7 @@ fn process_single_edge(
     let index = parse_char(key, special_char_mappings)?;
     edges
         .entry(value)
-        .or_insert_with(BTreeSet::new)
+        .or_default()
         .insert(index);
     Ok(())
 }
@@ -332,8 +333,8 @@ fn process_edge(
 /// - Converts single-character strings to their byte value.
 /// - Returns an error for invalid inputs.
 fn parse_char(s: &str, special_char_mappings: &BTreeMap<&str, u8>) -> Result<u8, CompilerError> {
-    if s.starts_with("\\x") {
-        u8::from_str_radix(&s[2..], 16)
+    if let Some(hex) = s.strip_prefix("\\x") {
+        u8::from_str_radix(hex, 16)
             .map_err(|e: ParseIntError| CompilerError::ParseError(e.to_string()))
     } else if let Some(&value) = special_char_mappings.get(s) {
         Ok(value)
@@ -363,7 +364,8 @@ fn parse_char(s: &str, special_char_mappings: &BTreeMap<&str, u8>) -> Result<u8,
 /// - Creates special character mappings.
 /// - Iterates over all edges, processing each one.
 /// - Handles the special case of space character representation.
-fn process_state_edges(
+/// - Drops byte 0, which the circuits reserve for padding, so padding never changes state.
+pub(crate) fn process_state_edges(
     state_edges: &BTreeMap<String, usize>,
 ) -> Result<BTreeMap<usize, BTreeSet<u8>>, CompilerError> {
     let mut edges = BTreeMap::new();
@@ -374,6 +376,11 @@ fn process_state_edges(
         process_edge(key, *value, &mut edges, &special_char_mappings)?;
     }
 
+    for char_set in edges.values_mut() {
+        char_set.remove(&0);
+    }
+    edges.retain(|_, char_set| !char_set.is_empty());
+
     Ok(edges)
 }
 
@@ -404,7 +411,7 @@ fn convert_dfa_to_graph(dfa: DFA<Vec<u32>>) -> Result<DFAGraph, CompilerError> {
     handle_eoi_transitions(&mut dfa_info);
 
     let start_state = find_start_state(&dfa_str)?;
-    dfa_info = sort_and_rename_states(&mut dfa_info, start_state);
+    dfa_info = sort_and_rename_states(&dfa_info, start_state);
 
     let mut graph = DFAGraph { states: Vec::new() };
     for state in &dfa_info.states {
@@ -429,6 +436,10 @@ fn convert_dfa_to_graph(dfa: DFA<Vec<u32>>) -> Result<DFAGraph, CompilerError> {
 ///
 /// A `Result` containing a `DFAGraph` or a `CompilerError`.
 fn create_dfa_graph_from_regex(regex: &str) -> Result<DFAGraph, CompilerError> {
+    // Parse the pattern on its own first: wrapping an unbalanced pattern such as `a)(?:b`
+    // below would turn it into a valid regex of another language
+    Regex::new(regex)?;
+
     let config = DFA::config()
         .minimize(true)
         .start_kind(StartKind::Anchored)
@@ -437,10 +448,10 @@ fn create_dfa_graph_from_regex(regex: &str) -> Result<DFAGraph, CompilerError> {
 
     let dfa = DFA::builder()
         .configure(config)
-        .build(&format!(r"^{}$", regex))
+        .build(&format!(r"^(?:{})$", regex))
         .map_err(|e| CompilerError::BuildError {
             regex: regex.to_string(),
-            source: e,
+            source: Box::new(e),
         })?;
 
     // println!("[1] dfa:   {:?}", dfa.clone());
@@ -459,7 +470,7 @@ fn create_dfa_graph_from_regex(regex: &str) -> Result<DFAGraph, CompilerError> {
 /// # Returns
 ///
 /// A `Result` containing a `RegexAndDFA` or a `CompilerError`.
-pub(crate) fn create_regex_and_dfa_from_str_and_defs(
+pub fn create_regex_and_dfa_from_str_and_defs(
     regex_str: &str,
     substrs_defs_json: SubstringDefinitionsJson,
 ) -> Result<RegexAndDFA, CompilerError> {
@@ -0,0 +1,193 @@
+use crate::structs::DFAGraph;
+use rand::Rng;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+/// Mutations `sample_rejected` tries before giving up.
+const MAX_ATTEMPTS: usize = 100;
+
+impl DFAGraph {
+    /// Samples a random string of at most `max_len` bytes that the graph accepts.
+    ///
+    /// # Function Behavior
+    ///
+    /// - Walks from the start state, taking a random transition and a random byte of it at
+    ///   every step, among the transitions that can still reach an accept state within
+    ///   `max_len` bytes.
+    /// - In an accept state, the walk stops with probability 1/2, or when it cannot go on.
+    /// - Byte 0 is padding and never sampled.
+    ///
+    /// Returns `None` if the graph accepts no string of at most `max_len` bytes.
+    pub fn sample_accepted<R: Rng + ?Sized>(&self, rng: &mut R, max_len: usize) -> Option<Vec<u8>> {
+        let distances = self.accept_distances();
+        distances.get(&0).filter(|&&distance| distance <= max_len)?;
+
+        let mut state_id = 0;
+        let mut input = vec![];
+        loop {
+            let remaining = max_len - input.len();
+            let moves: Vec<(usize, Vec<u8>)> = self
+                .moves(state_id)
+                .into_iter()
+                .filter(|(to_state, _)| distances.get(to_state).is_some_and(|&d| d < remaining))
+                .collect();
+            if self.is_accepting(state_id) && (moves.is_empty() || rng.gen_bool(0.5)) {
+                return Some(input);
+            }
+            // Not accepting, so an accept state is reachable in `remaining` bytes
+            let (to_state, bytes) = &moves[rng.gen_range(0..moves.len())];
+            input.push(bytes[rng.gen_range(0..bytes.len())]);
+            state_id = *to_state;
+        }
+    }
+
+    /// Samples a random string of at most `max_len` bytes that the graph rejects.
+    ///
+    /// # Function Behavior
+    ///
+    /// - Makes a near miss of a string from `sample_accepted`, or of the empty string if none
+    ///   fits, with one random mutation: replacing a byte by one its state has no transition
+    ///   for, so the run gets stuck there; truncating it, so the run may end in a
+    ///   non-accepting state; or appending a random byte.
+    /// - Keeps the first mutation the graph rejects, trying up to 100 times.
+    /// - Byte 0 is padding and never sampled.
+    ///
+    /// Returns `None` if no rejected string was found, e.g. when the graph accepts every
+    /// string of at most `max_len` bytes.
+    pub fn sample_rejected<R: Rng + ?Sized>(&self, rng: &mut R, max_len: usize) -> Option<Vec<u8>> {
+        for _ in 0..MAX_ATTEMPTS {
+            let mut input = self.sample_accepted(rng, max_len).unwrap_or_default();
+            match rng.gen_range(0..3) {
+                0 if !input.is_empty() => {
+                    let offset = rng.gen_range(0..input.len());
+                    let state_id = self.run(&input[..offset]).end_state();
+                    let taken: BTreeSet<u8> =
+                        self.moves(state_id).into_iter().flat_map(|(_, bytes)| bytes).collect();
+                    let missing: Vec<u8> = (1..=u8::MAX).filter(|b| !taken.contains(b)).collect();
+                    if missing.is_empty() {
+                        continue;
+                    }
+                    input[offset] = missing[rng.gen_range(0..missing.len())];
+                }
+                1 => input.truncate(rng.gen_range(0..=input.len())),
+                _ if input.len() < max_len => input.push(rng.gen_range(1..=u8::MAX)),
+                _ => continue,
+            }
+            if !self.run(&input).accepted {
+                return Some(input);
+            }
+        }
+        None
+    }
+
+    /// Enumerates the `k` shortest strings of at most `max_len` bytes that the graph accepts.
+    ///
+    /// # Function Behavior
+    ///
+    /// - Strings are listed by length. Strings of the same length are in lexicographic order,
+    ///   ranking alphanumeric bytes first, then other printable ASCII, then the rest, so the
+    ///   first strings are readable.
+    /// - Byte 0 is padding and never part of a string.
+    /// - Fewer than `k` strings are returned if the graph accepts fewer within `max_len`.
+    pub fn shortest_accepted(&self, k: usize, max_len: usize) -> Vec<Vec<u8>> {
+        let mut byte_order: Vec<u8> = (1..=u8::MAX).collect();
+        byte_order.sort_by_key(|&b| (!b.is_ascii_alphanumeric(), !b.is_ascii_graphic(), b));
+        let mut enumeration = Enumeration {
+            graph: self,
+            byte_order,
+            can_accept: vec![self
+                .states
+                .iter()
+                .filter(|s| s.state_type == "accept")
+                .map(|s| s.state_id)
+                .collect()],
+            accepted: vec![],
+            k,
+        };
+
+        for len in 0..=max_len {
+            if len > 0 {
+                let next = &enumeration.can_accept[len - 1];
+                let states = self
+                    .states
+                    .iter()
+                    .filter(|s| self.moves(s.state_id).iter().any(|(to, _)| next.contains(to)))
+                    .map(|s| s.state_id)
+                    .collect();
+                enumeration.can_accept.push(states);
+            }
+            enumeration.enumerate(0, len, &mut vec![]);
+            if enumeration.accepted.len() == k {
+                break;
+            }
+        }
+        enumeration.accepted
+    }
+
+    /// Returns the transitions of `state_id` as `(to_state, bytes)`, without padding.
+    pub(crate) fn moves(&self, state_id: usize) -> Vec<(usize, Vec<u8>)> {
+        self.states
+            .iter()
+            .filter(|s| s.state_id == state_id)
+            .flat_map(|s| &s.transitions)
+            .map(|(&to_state, char_set)| {
+                (to_state, char_set.iter().copied().filter(|&b| b != 0).collect::<Vec<_>>())
+            })
+            .filter(|(_, bytes)| !bytes.is_empty())
+            .collect()
+    }
+
+    /// Returns, for every state that can reach an accept state, the fewest bytes it takes.
+    fn accept_distances(&self) -> BTreeMap<usize, usize> {
+        let mut distances: BTreeMap<usize, usize> = self
+            .states
+            .iter()
+            .filter(|s| s.state_type == "accept")
+            .map(|s| (s.state_id, 0))
+            .collect();
+        let mut queue: VecDeque<usize> = distances.keys().copied().collect();
+        while let Some(to_state) = queue.pop_front() {
+            let distance = distances[&to_state] + 1;
+            for state in &self.states {
+                if !distances.contains_key(&state.state_id)
+                    && self.moves(state.state_id).iter().any(|(to, _)| *to == to_state)
+                {
+                    distances.insert(state.state_id, distance);
+                    queue.push_back(state.state_id);
+                }
+            }
+        }
+        distances
+    }
+}
+
+/// The state of `DFAGraph::shortest_accepted`.
+struct Enumeration<'a> {
+    graph: &'a DFAGraph,
+    byte_order: Vec<u8>,
+    /// `can_accept[n]` holds the states with an accepted continuation of exactly `n` bytes.
+    can_accept: Vec<BTreeSet<usize>>,
+    accepted: Vec<Vec<u8>>,
+    k: usize,
+}
+
+impl Enumeration<'_> {
+    /// Appends the continuations of `prefix` of exactly `remaining` bytes from `state_id`
+    /// that end in an accept state, in `byte_order`, until `k` strings are accepted.
+    fn enumerate(&mut self, state_id: usize, remaining: usize, prefix: &mut Vec<u8>) {
+        if self.accepted.len() == self.k || !self.can_accept[remaining].contains(&state_id) {
+            return;
+        }
+        if remaining == 0 {
+            self.accepted.push(prefix.clone());
+            return;
+        }
+        for index in 0..self.byte_order.len() {
+            let byte = self.byte_order[index];
+            if let Some(to_state) = self.graph.next_state(state_id, byte) {
+                prefix.push(byte);
+                self.enumerate(to_state, remaining - 1, prefix);
+                prefix.pop();
+            }
+        }
+    }
+}
@@ -0,0 +1,486 @@
+use crate::{
+    arkworks::gen_arkworks_allstr,
+    circuit::PACKED_CHUNK_BYTES,
+    errors::CompilerError,
+    prover::Curve,
+    structs::DFAGraph,
+};
+use proc_macro2::Literal;
+use rand::{rngs::StdRng, SeedableRng};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Maximum number of samples drawn per requested rejected example before `examples` returns
+/// fewer.
+const MAX_ATTEMPTS_PER_EXAMPLE: usize = 10;
+
+/// Options of the crate written by `gen_crate`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrateOptions {
+    /// Cargo package name; its library is named after it with `-` replaced by `_`.
+    pub name: String,
+    /// Maximum input length of the circuit.
+    pub max_len: usize,
+    /// Keep input bytes private and expose them packed into 31-byte public inputs.
+    pub packed_input: bool,
+    /// Pairing curve of the Groth16 prover.
+    pub curve: Curve,
+    /// Number of accepted and of rejected strings the generated tests check.
+    pub num_examples: usize,
+}
+
+impl Default for CrateOptions {
+    fn default() -> Self {
+        Self {
+            name: "regex_prover".to_string(),
+            max_len: 256,
+            packed_input: false,
+            curve: Curve::Bn254,
+            num_examples: 5,
+        }
+    }
+}
+
+/// Generates a standalone Cargo project proving matches of `regex_str` with Groth16.
+///
+/// # Arguments
+///
+/// * `dfa_graph` - The graph of `regex_str`.
+/// * `regex_str` - The regex, recorded in the generated library.
+/// * `options` - The package name, circuit size, input encoding, curve and example count.
+///
+/// # Returns
+///
+/// The contents of every file of the project, keyed by their path relative to its root,
+/// or a `CompilerError::CodegenError` if `options.name` is not a valid package name.
+///
+/// # Function Behavior
+///
+/// - `src/generated_circuit.rs` holds the circuit emitted by `gen_arkworks_allstr`, whose
+///   `MAX_LEN` constant is `options.max_len`.
+/// - `src/lib.rs` builds the circuit and its public inputs for an input string, and wraps
+///   Groth16 setup, prove and verify.
+/// - `src/main.rs` is a binary with `setup`, `prove <input>` and `verify <input>` commands,
+///   storing the proving key, verifying key and proof in files.
+/// - `tests/examples.rs` checks the circuit on strings accepted and rejected by
+///   `dfa_graph`, as found by `examples`.
+pub fn gen_crate(
+    dfa_graph: &DFAGraph,
+    regex_str: &str,
+    options: &CrateOptions,
+) -> Result<BTreeMap<PathBuf, String>, CompilerError> {
+    let lib_name = options.name.replace('-', "_");
+    let valid_name = options.name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && options.name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid_name || syn::parse_str::<syn::Ident>(&lib_name).is_err() {
+        return Err(CompilerError::CodegenError(format!(
+            "Invalid package name \"{}\"",
+            options.name
+        )));
+    }
+
+    let circuit = gen_arkworks_allstr(
+        dfa_graph,
+        "RegexCircuit",
+        regex_str,
+        Some(options.max_len),
+        options.packed_input,
+    )?;
+    let (accepted, rejected) = examples(dfa_graph, options.num_examples, options.max_len);
+
+    let mut files = BTreeMap::new();
+    files.insert(PathBuf::from("Cargo.toml"), generate_manifest(options));
+    files.insert(PathBuf::from("src/generated_circuit.rs"), circuit);
+    files.insert(PathBuf::from("src/lib.rs"), generate_lib(regex_str, options));
+    files.insert(PathBuf::from("src/main.rs"), fill(MAIN_TEMPLATE, &[("lib_name", &lib_name)]));
+    files.insert(
+        PathBuf::from("tests/examples.rs"),
+        generate_tests(&lib_name, &accepted, &rejected),
+    );
+    for (path, contents) in &files {
+        if path.extension().is_some_and(|ext| ext == "rs") {
+            syn::parse_file(contents).map_err(|e| {
+                CompilerError::CodegenError(format!("{} does not parse: {}", path.display(), e))
+            })?;
+        }
+    }
+    Ok(files)
+}
+
+/// Writes the `files` returned by `gen_crate` under `dir`, creating directories as needed.
+pub fn write_crate<P: AsRef<Path>>(
+    dir: P,
+    files: &BTreeMap<PathBuf, String>,
+) -> Result<(), CompilerError> {
+    for (path, contents) in files {
+        let path = dir.as_ref().join(path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)?;
+    }
+    Ok(())
+}
+
+/// Finds up to `count` strings `dfa_graph` accepts and up to `count` it rejects.
+///
+/// The accepted strings are the shortest ones, see `DFAGraph::shortest_accepted`. The
+/// rejected ones are near misses from `DFAGraph::sample_rejected`, with a fixed seed so a
+/// graph always gets the same examples. Every string is at most `max_len` bytes long.
+pub fn examples(dfa_graph: &DFAGraph, count: usize, max_len: usize) -> (Vec<Vec<u8>>, Vec<Vec<u8>>) {
+    let accepted = dfa_graph.shortest_accepted(count, max_len);
+
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut rejected = vec![];
+    for _ in 0..count * MAX_ATTEMPTS_PER_EXAMPLE {
+        if rejected.len() == count {
+            break;
+        }
+        match dfa_graph.sample_rejected(&mut rng, max_len) {
+            Some(input) if !rejected.contains(&input) => rejected.push(input),
+            Some(_) => {}
+            None => break,
+        }
+    }
+
+    (accepted, rejected)
+}
+
+/// The package and import names of `curve`'s crate.
+fn curve_crate(curve: Curve) -> (&'static str, &'static str, &'static str) {
+    match curve {
+        Curve::Bn254 => ("ark-bn254", "ark_bn254", "Bn254"),
+        Curve::Bls12_381 => ("ark-bls12-381", "ark_bls12_381", "Bls12_381"),
+    }
+}
+
+fn generate_manifest(options: &CrateOptions) -> String {
+    let (curve_package, _, _) = curve_crate(options.curve);
+    [
+        "[package]".to_string(),
+        format!("name = \"{}\"", options.name),
+        "version = \"0.1.0\"".to_string(),
+        "edition = \"2021\"".to_string(),
+        "".to_string(),
+        "[dependencies]".to_string(),
+        "ark-ff = \"0.4\"".to_string(),
+        "ark-relations = \"0.4\"".to_string(),
+        "ark-r1cs-std = \"0.4\"".to_string(),
+        "ark-serialize = \"0.4\"".to_string(),
+        "ark-snark = \"0.4\"".to_string(),
+        "ark-groth16 = \"0.4\"".to_string(),
+        format!("{} = \"0.4\"", curve_package),
+        "rand = \"0.8\"".to_string(),
+        "".to_string(),
+        "# Keep the project out of any enclosing workspace".to_string(),
+        "[workspace]".to_string(),
+        "".to_string(),
+        "[profile.dev.package.\"*\"]".to_string(),
+        "opt-level = 3".to_string(),
+        "".to_string(),
+        "[profile.test]".to_string(),
+        "opt-level = 3".to_string(),
+        "".to_string(),
+    ]
+    .join("\n")
+}
+
+fn generate_lib(regex_str: &str, options: &CrateOptions) -> String {
+    let (_, curve_crate, pairing) = curve_crate(options.curve);
+    let public_inputs = if options.packed_input {
+        PACKED_PUBLIC_INPUTS
+    } else {
+        BYTE_PUBLIC_INPUTS
+    };
+    let public_inputs = public_inputs.replace("{chunk_bytes}", &PACKED_CHUNK_BYTES.to_string());
+    fill(
+        LIB_TEMPLATE,
+        &[
+            ("regex", &Literal::string(regex_str).to_string()),
+            ("curve_crate", curve_crate),
+            ("pairing", pairing),
+            ("public_inputs", public_inputs.trim_start()),
+        ],
+    )
+}
+
+fn generate_tests(lib_name: &str, accepted: &[Vec<u8>], rejected: &[Vec<u8>]) -> String {
+    let literals = |inputs: &[Vec<u8>]| {
+        inputs
+            .iter()
+            .map(|input| format!("\n    {},", Literal::byte_string(input)))
+            .chain(inputs.first().map(|_| "\n".to_string()))
+            .collect::<String>()
+    };
+    fill(
+        TESTS_TEMPLATE,
+        &[
+            ("lib_name", lib_name),
+            ("accepted", &literals(accepted)),
+            ("rejected", &literals(rejected)),
+        ],
+    )
+}
+
+/// Replaces every `{key}` of `template` by its value, leaving other braces alone.
+///
+/// Values are inserted as is, so a value holding `{key}` is not replaced again.
+fn fill(template: &str, values: &[(&str, &str)]) -> String {
+    let mut filled = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        filled.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let placeholder = values.iter().find(|(key, _)| {
+            rest[1..].starts_with(key) && rest[1 + key.len()..].starts_with('}')
+        });
+        match placeholder {
+            Some((key, value)) => {
+                filled.push_str(value);
+                rest = &rest[key.len() + 2..];
+            }
+            None => {
+                filled.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+    filled.push_str(rest);
+    filled
+}
+
+const LIB_TEMPLATE: &str = r#"//! Groth16 prover for the regex circuit in `generated_circuit.rs`.
+
+mod generated_circuit;
+
+use ark_groth16::{Groth16, Proof, ProvingKey, VerifyingKey};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystem, SynthesisError};
+use ark_snark::SNARK;
+use {curve_crate}::{{pairing} as Curve, Fr};
+use rand::{CryptoRng, RngCore};
+
+pub use generated_circuit::RegexCircuit;
+
+/// The regex the circuit was generated from.
+pub const REGEX: &str = {regex};
+
+/// Maximum input length of the circuit, in bytes.
+pub const MAX_LEN: usize = RegexCircuit::<Fr>::MAX_LEN;
+
+/// Builds the circuit assigned to `input`.
+pub fn circuit(input: &[u8]) -> RegexCircuit<Fr> {
+    RegexCircuit {
+        input: input.iter().map(|&b| Fr::from(b as u64)).collect(),
+        max_len: MAX_LEN,
+    }
+}
+
+{public_inputs}
+/// Returns whether `input` satisfies the circuit, i.e. matches `REGEX`.
+pub fn is_satisfied(input: &[u8]) -> Result<bool, SynthesisError> {
+    if input.len() > MAX_LEN {
+        return Ok(false);
+    }
+    let cs = ConstraintSystem::<Fr>::new_ref();
+    circuit(input).generate_constraints(cs.clone())?;
+    cs.is_satisfied()
+}
+
+/// Generates the proving and verifying keys of the circuit.
+pub fn setup<R: RngCore + CryptoRng>(
+    rng: &mut R,
+) -> Result<(ProvingKey<Curve>, VerifyingKey<Curve>), SynthesisError> {
+    Groth16::<Curve>::circuit_specific_setup(circuit(&[]), rng)
+}
+
+/// Proves that `input` matches `REGEX`; check `is_satisfied` first, as the proof of a
+/// non-matching input does not verify.
+pub fn prove<R: RngCore + CryptoRng>(
+    pk: &ProvingKey<Curve>,
+    input: &[u8],
+    rng: &mut R,
+) -> Result<Proof<Curve>, SynthesisError> {
+    Groth16::<Curve>::prove(pk, circuit(input), rng)
+}
+
+/// Verifies `proof` against the public inputs of `input`; an input longer than `MAX_LEN`
+/// never verifies.
+pub fn verify(
+    vk: &VerifyingKey<Curve>,
+    input: &[u8],
+    proof: &Proof<Curve>,
+) -> Result<bool, SynthesisError> {
+    match public_inputs(input) {
+        Some(public_inputs) => Groth16::<Curve>::verify(vk, &public_inputs, proof),
+        None => Ok(false),
+    }
+}
+"#;
+
+const BYTE_PUBLIC_INPUTS: &str = r#"
+/// Builds the public inputs of `input`: one field element per byte, zero-padded to
+/// `MAX_LEN`. Returns `None` if `input` is longer than `MAX_LEN`.
+pub fn public_inputs(input: &[u8]) -> Option<Vec<Fr>> {
+    if input.len() > MAX_LEN {
+        return None;
+    }
+    let mut bytes = input.to_vec();
+    bytes.resize(MAX_LEN, 0);
+    Some(bytes.into_iter().map(|b| Fr::from(b as u64)).collect())
+}
+"#;
+
+const PACKED_PUBLIC_INPUTS: &str = r#"
+/// Builds the public inputs of `input`: its bytes, zero-padded to `MAX_LEN` and packed
+/// into little-endian chunks of 31 bytes. Returns `None` if `input` is longer than
+/// `MAX_LEN`.
+pub fn public_inputs(input: &[u8]) -> Option<Vec<Fr>> {
+    use ark_ff::PrimeField;
+
+    if input.len() > MAX_LEN {
+        return None;
+    }
+    let mut bytes = input.to_vec();
+    bytes.resize(MAX_LEN, 0);
+    Some(bytes.chunks({chunk_bytes}).map(Fr::from_le_bytes_mod_order).collect())
+}
+"#;
+
+const MAIN_TEMPLATE: &str = r#"use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use rand::rngs::OsRng;
+use {lib_name}::{is_satisfied, prove, setup, verify, MAX_LEN, REGEX};
+use std::{
+    env,
+    error::Error,
+    fs::{self, File},
+    io::{BufReader, BufWriter},
+    path::{Path, PathBuf},
+    process,
+};
+
+const PROVING_KEY: &str = "proving_key.bin";
+const VERIFYING_KEY: &str = "verifying_key.bin";
+const PROOF: &str = "proof.bin";
+
+const USAGE: &str = "Usage:
+    setup [dir]           Write proving_key.bin and verifying_key.bin to dir
+    prove <input> [dir]   Prove that input matches the regex, writing proof.bin to dir
+    verify <input> [dir]  Verify proof.bin against input";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let result = match args.as_slice() {
+        ["setup", dir @ ..] if dir.len() <= 1 => run_setup(&key_dir(dir)),
+        ["prove", input, dir @ ..] if dir.len() <= 1 => run_prove(input, &key_dir(dir)),
+        ["verify", input, dir @ ..] if dir.len() <= 1 => run_verify(input, &key_dir(dir)),
+        _ => {
+            eprintln!("Regex: {}
+{}", REGEX, USAGE);
+            process::exit(2);
+        }
+    };
+    match result {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// The directory holding the key and proof files, `.` by default.
+fn key_dir(dir: &[&str]) -> PathBuf {
+    PathBuf::from(dir.first().copied().unwrap_or("."))
+}
+
+fn run_setup(dir: &Path) -> Result<bool, Box<dyn Error>> {
+    fs::create_dir_all(dir)?;
+    let (pk, vk) = setup(&mut OsRng)?;
+    write(&dir.join(PROVING_KEY), &pk)?;
+    write(&dir.join(VERIFYING_KEY), &vk)?;
+    println!("Proving and verifying keys saved to '{}'", dir.display());
+    Ok(true)
+}
+
+fn run_prove(input: &str, dir: &Path) -> Result<bool, Box<dyn Error>> {
+    if input.len() > MAX_LEN {
+        return Err(format!("Input is longer than {} bytes", MAX_LEN).into());
+    }
+    if !is_satisfied(input.as_bytes())? {
+        return Err(format!("{:?} does not match {}", input, REGEX).into());
+    }
+    let pk = read(&dir.join(PROVING_KEY))?;
+    let proof = prove(&pk, input.as_bytes(), &mut OsRng)?;
+    write(&dir.join(PROOF), &proof)?;
+    println!("Proof saved to '{}'", dir.join(PROOF).display());
+    Ok(true)
+}
+
+fn run_verify(input: &str, dir: &Path) -> Result<bool, Box<dyn Error>> {
+    if input.len() > MAX_LEN {
+        return Err(format!("Input is longer than {} bytes", MAX_LEN).into());
+    }
+    let vk = read(&dir.join(VERIFYING_KEY))?;
+    let proof = read(&dir.join(PROOF))?;
+    let is_valid = verify(&vk, input.as_bytes(), &proof)?;
+    println!("Verification result: {}", is_valid);
+    Ok(is_valid)
+}
+
+fn write<T: CanonicalSerialize>(path: &Path, value: &T) -> Result<(), Box<dyn Error>> {
+    value.serialize_compressed(BufWriter::new(File::create(path)?))?;
+    Ok(())
+}
+
+fn read<T: CanonicalDeserialize>(path: &Path) -> Result<T, Box<dyn Error>> {
+    Ok(T::deserialize_compressed(BufReader::new(File::open(path)?))?)
+}
+"#;
+
+const TESTS_TEMPLATE: &str = r#"use rand::rngs::OsRng;
+use {lib_name}::{is_satisfied, prove, setup, verify, MAX_LEN};
+
+/// Strings the regex accepts.
+const ACCEPTED: &[&[u8]] = &[{accepted}];
+
+/// Strings the regex rejects.
+const REJECTED: &[&[u8]] = &[{rejected}];
+
+#[test]
+fn accepted_examples_satisfy_the_circuit() {
+    for input in ACCEPTED {
+        assert!(is_satisfied(input).unwrap(), "{}", input.escape_ascii());
+    }
+}
+
+#[test]
+fn rejected_examples_do_not_satisfy_the_circuit() {
+    for input in REJECTED {
+        assert!(!is_satisfied(input).unwrap(), "{}", input.escape_ascii());
+    }
+}
+
+#[test]
+fn proofs_only_verify_for_the_proven_input() {
+    let Some((input, others)) = ACCEPTED.split_first() else {
+        return;
+    };
+    let (pk, vk) = setup(&mut OsRng).unwrap();
+    let proof = prove(&pk, input, &mut OsRng).unwrap();
+    assert!(verify(&vk, input, &proof).unwrap());
+    for other in others.iter().chain(REJECTED) {
+        assert!(!verify(&vk, other, &proof).unwrap(), "{}", other.escape_ascii());
+    }
+
+    // Bytes past MAX_LEN are not dropped to match the proven input
+    let mut longer = input.to_vec();
+    longer.resize(MAX_LEN, 0);
+    longer.push(b'x');
+    assert!(!verify(&vk, &longer, &proof).unwrap());
+}
+"#;
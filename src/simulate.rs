@@ -0,0 +1,120 @@
+use crate::{
+    errors::CompilerError,
+    structs::{DFAGraph, Trace},
+};
+
+impl DFAGraph {
+    /// Runs the graph natively on `input`, starting from the start state 0.
+    ///
+    /// See `run_from`.
+    pub fn run(&self, input: &[u8]) -> Trace {
+        self.run_from(0, input)
+    }
+
+    /// Runs the graph natively on `input`, starting from `start_state`.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_state` - The `state_id` to start from.
+    /// * `input` - The bytes to consume.
+    ///
+    /// # Returns
+    ///
+    /// A `Trace` holding every state visited, starting with `start_state`, the offset of the
+    /// byte the run got stuck on if any, and whether the run ended in an accept state.
+    ///
+    /// # Function Behavior
+    ///
+    /// - Follows the rules of the generated circuits: byte 0 is padding, which leaves the
+    ///   state unchanged and may only be followed by more padding.
+    /// - Stops at the first other byte without a transition, or following padding; a stuck
+    ///   run never accepts.
+    pub fn run_from(&self, start_state: usize, input: &[u8]) -> Trace {
+        let mut states = vec![start_state];
+        let mut current_state = start_state;
+        let mut padding_started = false;
+
+        for (offset, &byte) in input.iter().enumerate() {
+            let next_state = if padding_started && byte != 0 {
+                None
+            } else {
+                self.next_state(current_state, byte)
+            };
+            padding_started |= byte == 0;
+            current_state = match next_state {
+                Some(to_state) => to_state,
+                None if byte == 0 => current_state,
+                None => {
+                    return Trace {
+                        states,
+                        stuck_at: Some(offset),
+                        accepted: false,
+                    }
+                }
+            };
+            states.push(current_state);
+        }
+
+        Trace {
+            accepted: self.is_accepting(current_state),
+            states,
+            stuck_at: None,
+        }
+    }
+
+    /// Runs the graph on `input` and turns a rejection into a `CompilerError`.
+    ///
+    /// The error names the offset and value of the byte without a transition, or the
+    /// non-accepting state the input ended in.
+    pub fn check(&self, input: &[u8]) -> Result<Trace, CompilerError> {
+        let trace = self.run(input);
+        if let Some(offset) = trace.stuck_at.filter(|&offset| input[..offset].contains(&0)) {
+            return Err(CompilerError::InputRejected(format!(
+                "byte {} at offset {} follows padding (byte 0)",
+                input[offset], offset
+            )));
+        }
+        if let Some(offset) = trace.stuck_at {
+            return Err(CompilerError::InputRejected(format!(
+                "no transition from state {} on byte {} ({:?}) at offset {}",
+                trace.end_state(),
+                input[offset],
+                input[offset] as char,
+                offset
+            )));
+        }
+        if !trace.accepted {
+            return Err(CompilerError::InputRejected(format!(
+                "input ended in non-accepting state {} after {} bytes",
+                trace.end_state(),
+                input.len()
+            )));
+        }
+        Ok(trace)
+    }
+
+    /// Returns the state reached from `state_id` on `byte`, if there is a transition.
+    pub fn next_state(&self, state_id: usize, byte: u8) -> Option<usize> {
+        self.states
+            .iter()
+            .find(|s| s.state_id == state_id)?
+            .transitions
+            .iter()
+            .find(|(_, char_set)| char_set.contains(&byte))
+            .map(|(&to_state, _)| to_state)
+    }
+
+    /// Returns whether `state_id` is an accept state.
+    pub fn is_accepting(&self, state_id: usize) -> bool {
+        self.states
+            .iter()
+            .any(|s| s.state_id == state_id && s.state_type == "accept")
+    }
+}
+
+impl Trace {
+    /// The last state the run reached; the state it got stuck in if it did not finish.
+    pub fn end_state(&self) -> usize {
+        *self.states.last().expect("a trace always holds its start state")
+    }
+}
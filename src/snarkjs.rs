@@ -0,0 +1,300 @@
+use crate::{
+    errors::CompilerError,
+    structs::{SnarkjsProof, SnarkjsVerifyingKey},
+};
+use ark_bn254::{Bn254, Fq, Fq2, Fr, G1Affine, G2Affine};
+use ark_ec::{pairing::Pairing, AffineRepr};
+use ark_ff::{PrimeField, Zero};
+use ark_groth16::{Proof, VerifyingKey};
+use num_bigint::BigUint;
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::Path,
+};
+
+const SNARKJS_PROTOCOL: &str = "groth16";
+const SNARKJS_CURVE: &str = "bn128";
+
+/// Formats a prime field element as the decimal string snarkjs expects.
+fn field_to_string<F: PrimeField>(value: &F) -> String {
+    value.into_bigint().to_string()
+}
+
+/// Parses a decimal string produced by snarkjs into a prime field element.
+///
+/// Only canonical values are accepted: a value of the modulus or more is an error rather than
+/// being reduced, so every field element has a single encoding.
+fn field_from_string<F: PrimeField>(value: &str) -> Result<F, CompilerError> {
+    let invalid = || CompilerError::SnarkjsError(format!("Invalid field element: \"{}\"", value));
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let value: BigUint = value.parse().map_err(|_| invalid())?;
+    if value >= F::MODULUS.into() {
+        return Err(invalid());
+    }
+    Ok(F::from(value))
+}
+
+/// Converts a G1 point into snarkjs's projective `[x, y, z]` layout.
+fn g1_to_snarkjs(point: &G1Affine) -> Vec<String> {
+    match point.xy() {
+        Some((x, y)) => vec![field_to_string(x), field_to_string(y), "1".to_string()],
+        None => vec!["0".to_string(), "1".to_string(), "0".to_string()],
+    }
+}
+
+/// Converts a G2 point into snarkjs's `[[x.c0, x.c1], [y.c0, y.c1], [z.c0, z.c1]]` layout.
+fn g2_to_snarkjs(point: &G2Affine) -> Vec<Vec<String>> {
+    match point.xy() {
+        Some((x, y)) => vec![
+            fq2_to_snarkjs(x),
+            fq2_to_snarkjs(y),
+            vec!["1".to_string(), "0".to_string()],
+        ],
+        None => vec![
+            vec!["0".to_string(), "0".to_string()],
+            vec!["1".to_string(), "0".to_string()],
+            vec!["0".to_string(), "0".to_string()],
+        ],
+    }
+}
+
+fn fq2_to_snarkjs(value: &Fq2) -> Vec<String> {
+    vec![field_to_string(&value.c0), field_to_string(&value.c1)]
+}
+
+fn fq2_from_snarkjs(value: &[String]) -> Result<Fq2, CompilerError> {
+    match value {
+        [c0, c1] => Ok(Fq2::new(field_from_string(c0)?, field_from_string(c1)?)),
+        _ => Err(CompilerError::SnarkjsError(
+            "Expected an Fq2 element with 2 coordinates".to_string(),
+        )),
+    }
+}
+
+/// Parses a snarkjs G1 point, checking that it lies on the curve and in the prime-order subgroup.
+fn g1_from_snarkjs(point: &[String]) -> Result<G1Affine, CompilerError> {
+    let [x, y, z] = point else {
+        return Err(CompilerError::SnarkjsError(
+            "Expected a G1 point with 3 coordinates".to_string(),
+        ));
+    };
+
+    let z: Fq = field_from_string(z)?;
+    if z.is_zero() {
+        return Ok(G1Affine::zero());
+    }
+    if z != Fq::from(1u64) {
+        return Err(CompilerError::SnarkjsError(
+            "G1 point is not in affine form (z != 1)".to_string(),
+        ));
+    }
+
+    let point = G1Affine::new_unchecked(field_from_string(x)?, field_from_string(y)?);
+    if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(CompilerError::SnarkjsError(
+            "G1 point is not in the BN254 subgroup".to_string(),
+        ));
+    }
+
+    Ok(point)
+}
+
+/// Parses a snarkjs G2 point, checking that it lies on the curve and in the prime-order subgroup.
+fn g2_from_snarkjs(point: &[Vec<String>]) -> Result<G2Affine, CompilerError> {
+    let [x, y, z] = point else {
+        return Err(CompilerError::SnarkjsError(
+            "Expected a G2 point with 3 coordinates".to_string(),
+        ));
+    };
+
+    let z = fq2_from_snarkjs(z)?;
+    if z.is_zero() {
+        return Ok(G2Affine::zero());
+    }
+    if z != Fq2::from(1u64) {
+        return Err(CompilerError::SnarkjsError(
+            "G2 point is not in affine form (z != 1)".to_string(),
+        ));
+    }
+
+    let point = G2Affine::new_unchecked(fq2_from_snarkjs(x)?, fq2_from_snarkjs(y)?);
+    if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(CompilerError::SnarkjsError(
+            "G2 point is not in the BN254 subgroup".to_string(),
+        ));
+    }
+
+    Ok(point)
+}
+
+/// Checks the `protocol` and `curve` tags shared by snarkjs key and proof files.
+fn check_header(protocol: &str, curve: &str) -> Result<(), CompilerError> {
+    if protocol != SNARKJS_PROTOCOL {
+        return Err(CompilerError::SnarkjsError(format!(
+            "Unsupported protocol: \"{}\"",
+            protocol
+        )));
+    }
+    if curve != SNARKJS_CURVE {
+        return Err(CompilerError::SnarkjsError(format!(
+            "Unsupported curve: \"{}\"",
+            curve
+        )));
+    }
+    Ok(())
+}
+
+/// Converts an arkworks Groth16 verifying key into snarkjs's `verification_key.json` layout.
+///
+/// # Arguments
+///
+/// * `vk` - The BN254 verifying key to convert.
+///
+/// # Returns
+///
+/// A `SnarkjsVerifyingKey` ready to be serialized.
+///
+/// # Function Behavior
+///
+/// - Writes every curve point in snarkjs's projective layout with `z = 1`.
+/// - Computes `vk_alphabeta_12` as `e(alpha, beta)`, which snarkjs stores alongside the key.
+/// - Sets `nPublic` to the number of public inputs, i.e. `IC.len() - 1`.
+pub fn verifying_key_to_snarkjs(vk: &VerifyingKey<Bn254>) -> SnarkjsVerifyingKey {
+    let alphabeta = Bn254::pairing(vk.alpha_g1, vk.beta_g2).0;
+    let vk_alphabeta_12 = [alphabeta.c0, alphabeta.c1]
+        .iter()
+        .map(|fq6| [fq6.c0, fq6.c1, fq6.c2].iter().map(fq2_to_snarkjs).collect())
+        .collect();
+
+    SnarkjsVerifyingKey {
+        protocol: SNARKJS_PROTOCOL.to_string(),
+        curve: SNARKJS_CURVE.to_string(),
+        n_public: vk.gamma_abc_g1.len().saturating_sub(1),
+        vk_alpha_1: g1_to_snarkjs(&vk.alpha_g1),
+        vk_beta_2: g2_to_snarkjs(&vk.beta_g2),
+        vk_gamma_2: g2_to_snarkjs(&vk.gamma_g2),
+        vk_delta_2: g2_to_snarkjs(&vk.delta_g2),
+        vk_alphabeta_12,
+        ic: vk.gamma_abc_g1.iter().map(g1_to_snarkjs).collect(),
+    }
+}
+
+/// Converts a snarkjs `verification_key.json` back into an arkworks Groth16 verifying key.
+///
+/// # Arguments
+///
+/// * `vk` - The parsed snarkjs verifying key.
+///
+/// # Returns
+///
+/// A `Result` containing the BN254 `VerifyingKey`, or a `CompilerError` if the key is malformed.
+///
+/// # Function Behavior
+///
+/// - Rejects keys whose protocol is not `groth16` or whose curve is not `bn128`.
+/// - Checks that `nPublic` agrees with the length of `IC`.
+/// - Validates every point; `vk_alphabeta_12` is ignored since it is derived from alpha and beta.
+pub fn verifying_key_from_snarkjs(
+    vk: &SnarkjsVerifyingKey,
+) -> Result<VerifyingKey<Bn254>, CompilerError> {
+    check_header(&vk.protocol, &vk.curve)?;
+
+    if vk.ic.len() != vk.n_public + 1 {
+        return Err(CompilerError::SnarkjsError(format!(
+            "nPublic is {} but IC has {} points",
+            vk.n_public,
+            vk.ic.len()
+        )));
+    }
+
+    Ok(VerifyingKey {
+        alpha_g1: g1_from_snarkjs(&vk.vk_alpha_1)?,
+        beta_g2: g2_from_snarkjs(&vk.vk_beta_2)?,
+        gamma_g2: g2_from_snarkjs(&vk.vk_gamma_2)?,
+        delta_g2: g2_from_snarkjs(&vk.vk_delta_2)?,
+        gamma_abc_g1: vk
+            .ic
+            .iter()
+            .map(|point| g1_from_snarkjs(point))
+            .collect::<Result<Vec<_>, _>>()?,
+    })
+}
+
+/// Converts an arkworks Groth16 proof into snarkjs's `proof.json` layout.
+pub fn proof_to_snarkjs(proof: &Proof<Bn254>) -> SnarkjsProof {
+    SnarkjsProof {
+        pi_a: g1_to_snarkjs(&proof.a),
+        pi_b: g2_to_snarkjs(&proof.b),
+        pi_c: g1_to_snarkjs(&proof.c),
+        protocol: SNARKJS_PROTOCOL.to_string(),
+        curve: SNARKJS_CURVE.to_string(),
+    }
+}
+
+/// Converts a snarkjs `proof.json` back into an arkworks Groth16 proof.
+pub fn proof_from_snarkjs(proof: &SnarkjsProof) -> Result<Proof<Bn254>, CompilerError> {
+    check_header(&proof.protocol, &proof.curve)?;
+
+    Ok(Proof {
+        a: g1_from_snarkjs(&proof.pi_a)?,
+        b: g2_from_snarkjs(&proof.pi_b)?,
+        c: g1_from_snarkjs(&proof.pi_c)?,
+    })
+}
+
+/// Converts public inputs into snarkjs's `public.json` layout (a list of decimal strings).
+pub fn public_inputs_to_snarkjs(inputs: &[Fr]) -> Vec<String> {
+    inputs.iter().map(field_to_string).collect()
+}
+
+/// Converts a snarkjs `public.json` back into public inputs.
+pub fn public_inputs_from_snarkjs(inputs: &[String]) -> Result<Vec<Fr>, CompilerError> {
+    inputs.iter().map(|input| field_from_string(input)).collect()
+}
+
+fn write_json<T: serde::Serialize>(value: &T, path: &Path) -> Result<(), CompilerError> {
+    let file = File::create(path)?;
+    serde_json::to_writer_pretty(BufWriter::new(file), value)?;
+    Ok(())
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T, CompilerError> {
+    let file = File::open(path)?;
+    Ok(serde_json::from_reader(BufReader::new(file))?)
+}
+
+/// Writes `vk` to `path` as a snarkjs `verification_key.json`.
+pub fn write_verifying_key<P: AsRef<Path>>(
+    vk: &VerifyingKey<Bn254>,
+    path: P,
+) -> Result<(), CompilerError> {
+    write_json(&verifying_key_to_snarkjs(vk), path.as_ref())
+}
+
+/// Reads a snarkjs `verification_key.json` from `path`.
+pub fn read_verifying_key<P: AsRef<Path>>(path: P) -> Result<VerifyingKey<Bn254>, CompilerError> {
+    verifying_key_from_snarkjs(&read_json(path.as_ref())?)
+}
+
+/// Writes `proof` to `path` as a snarkjs `proof.json`.
+pub fn write_proof<P: AsRef<Path>>(proof: &Proof<Bn254>, path: P) -> Result<(), CompilerError> {
+    write_json(&proof_to_snarkjs(proof), path.as_ref())
+}
+
+/// Reads a snarkjs `proof.json` from `path`.
+pub fn read_proof<P: AsRef<Path>>(path: P) -> Result<Proof<Bn254>, CompilerError> {
+    proof_from_snarkjs(&read_json(path.as_ref())?)
+}
+
+/// Writes `inputs` to `path` as a snarkjs `public.json`.
+pub fn write_public_inputs<P: AsRef<Path>>(inputs: &[Fr], path: P) -> Result<(), CompilerError> {
+    write_json(&public_inputs_to_snarkjs(inputs), path.as_ref())
+}
+
+/// Reads a snarkjs `public.json` from `path`.
+pub fn read_public_inputs<P: AsRef<Path>>(path: P) -> Result<Vec<Fr>, CompilerError> {
+    public_inputs_from_snarkjs(&read_json::<Vec<String>>(path.as_ref())?)
+}
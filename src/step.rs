@@ -0,0 +1,189 @@
+use crate::{circuit::dfa_run_from, errors::CompilerError, structs::DFAGraph};
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    boolean::Boolean,
+    eq::EqGadget,
+    fields::{fp::FpVar, FieldVar},
+};
+use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError};
+
+/// The step function of an incrementally verifiable computation.
+///
+/// Folding schemes such as Nova repeatedly apply one step `z_{i+1} = F(z_i, w_i)` to a
+/// running state `z_i`, where `w_i` are external inputs supplied by the prover at each step.
+/// The native and in-circuit versions must compute the same function.
+pub trait StepCircuit<F: PrimeField> {
+    /// Number of field elements in the running state.
+    fn state_len(&self) -> usize;
+
+    /// Number of field elements consumed from the external inputs at every step.
+    fn external_inputs_len(&self) -> usize;
+
+    /// Computes the next state natively.
+    fn step_native(&self, state: &[F], external_inputs: &[F]) -> Result<Vec<F>, CompilerError>;
+
+    /// Emits the constraints of one step and returns the variables of the next state.
+    fn generate_step_constraints(
+        &self,
+        cs: ConstraintSystemRef<F>,
+        state: &[FpVar<F>],
+        external_inputs: &[FpVar<F>],
+    ) -> Result<Vec<FpVar<F>>, SynthesisError>;
+}
+
+/// Applies a block of `block_size` input bytes to the current DFA state.
+///
+/// The running state is `[current_state, padding_started]`, starting from `initial_state`,
+/// and the external inputs are the bytes of the block, one per field element. Each byte is
+/// applied with the same constraints as one iteration of the generated circuit's loop; a
+/// byte without a transition makes the step unsatisfiable, and byte 0 is padding that
+/// leaves the state unchanged but may only be followed by more padding. `padding_started`
+/// carries that across steps, so a padded block cannot be followed by more input.
+/// Acceptance of the final state is checked once the folding is done, see `is_accepting`.
+#[derive(Debug, Clone)]
+pub struct DFAStepCircuit {
+    pub dfa: DFAGraph,
+    pub block_size: usize,
+}
+
+impl DFAStepCircuit {
+    /// Creates a step circuit consuming `block_size` bytes of input per step.
+    ///
+    /// Returns a `CompilerError` if `block_size` is 0.
+    pub fn new(dfa: DFAGraph, block_size: usize) -> Result<Self, CompilerError> {
+        if block_size == 0 {
+            return Err(CompilerError::GenericError(
+                "Block size must be positive".to_string(),
+            ));
+        }
+        Ok(Self { dfa, block_size })
+    }
+
+    /// Splits `input` into zero-padded blocks, the external inputs of consecutive steps.
+    pub fn external_inputs<F: PrimeField>(&self, input: &[u8]) -> Vec<Vec<F>> {
+        input
+            .chunks(self.block_size)
+            .map(|block| {
+                let mut block: Vec<F> = block.iter().map(|&b| F::from(b as u64)).collect();
+                block.resize(self.block_size, F::zero());
+                block
+            })
+            .collect()
+    }
+
+    /// Returns the running state before the first step: state 0, no padding seen.
+    pub fn initial_state<F: PrimeField>(&self) -> Vec<F> {
+        vec![F::zero(), F::zero()]
+    }
+
+    /// Returns whether `state`, a running state of this circuit, is in an accept state.
+    pub fn is_accepting<F: PrimeField>(&self, state: &[F]) -> bool {
+        state.len() == 2
+            && self
+                .dfa
+                .states
+                .iter()
+                .any(|s| s.state_type == "accept" && state[0] == F::from(s.state_id as u64))
+    }
+
+    fn check_lengths<T>(&self, state: &[T], external_inputs: &[T]) -> Result<(), CompilerError> {
+        if state.len() != 2 || external_inputs.len() != self.block_size {
+            return Err(CompilerError::GenericError(format!(
+                "Expected a state of 2 elements and {} external inputs, got {} and {}",
+                self.block_size,
+                state.len(),
+                external_inputs.len()
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> StepCircuit<F> for DFAStepCircuit {
+    fn state_len(&self) -> usize {
+        2
+    }
+
+    fn external_inputs_len(&self) -> usize {
+        self.block_size
+    }
+
+    fn step_native(&self, state: &[F], external_inputs: &[F]) -> Result<Vec<F>, CompilerError> {
+        self.check_lengths(state, external_inputs)?;
+
+        let mut current_state = state[0];
+        let mut padding_started = match state[1] {
+            flag if flag.is_zero() => false,
+            flag if flag.is_one() => true,
+            flag => {
+                return Err(CompilerError::GenericError(format!(
+                    "Padding flag {} is not 0 or 1",
+                    flag.into_bigint()
+                )))
+            }
+        };
+        for &byte in external_inputs {
+            if padding_started && !byte.is_zero() {
+                return Err(CompilerError::GenericError(format!(
+                    "Input {} follows padding",
+                    byte.into_bigint()
+                )));
+            }
+            padding_started |= byte.is_zero();
+
+            let next_state = self
+                .dfa
+                .states
+                .iter()
+                .filter(|s| F::from(s.state_id as u64) == current_state)
+                .flat_map(|s| s.transitions.iter())
+                .find(|(_, char_set)| char_set.iter().any(|&c| F::from(c as u64) == byte))
+                .map(|(&to_state, _)| F::from(to_state as u64));
+            current_state = match next_state {
+                Some(to_state) => to_state,
+                None if byte.is_zero() => current_state,
+                None => {
+                    return Err(CompilerError::GenericError(format!(
+                        "No transition from state {} on input {}",
+                        current_state.into_bigint(),
+                        byte.into_bigint()
+                    )))
+                }
+            };
+        }
+        Ok(vec![current_state, F::from(padding_started)])
+    }
+
+    fn generate_step_constraints(
+        &self,
+        _cs: ConstraintSystemRef<F>,
+        state: &[FpVar<F>],
+        external_inputs: &[FpVar<F>],
+    ) -> Result<Vec<FpVar<F>>, SynthesisError> {
+        self.check_lengths(state, external_inputs)
+            .map_err(|_| SynthesisError::Unsatisfiable)?;
+
+        // The flag must be 0 or 1: it equals the boolean of whether it is one
+        let padding_started = state[1].is_one()?;
+        FpVar::from(padding_started.clone()).enforce_equal(&state[1])?;
+
+        let run = dfa_run_from(&self.dfa, state[0].clone(), padding_started, external_inputs)?;
+        run.valid.enforce_equal(&Boolean::constant(true))?;
+        Ok(vec![run.end_state, run.padding_started.into()])
+    }
+}
+
+/// Folds `steps` natively: applies `step_native` to `initial_state` once per block of inputs.
+///
+/// This is the reference an IVC proof over the same circuit must agree with.
+pub fn fold_native<F: PrimeField, C: StepCircuit<F>>(
+    circuit: &C,
+    initial_state: &[F],
+    steps: &[Vec<F>],
+) -> Result<Vec<F>, CompilerError> {
+    steps
+        .iter()
+        .try_fold(initial_state.to_vec(), |state, external_inputs| {
+            circuit.step_native(&state, external_inputs)
+        })
+}
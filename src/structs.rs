@@ -24,14 +24,14 @@ pub struct DFAGraphInfo {
     pub states: Vec<DFAStateInfo>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DFAStateNode {
     pub state_type: String,
     pub state_id: usize,
     pub transitions: BTreeMap<usize, BTreeSet<u8>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DFAGraph {
     pub states: Vec<DFAStateNode>,
 }
@@ -54,3 +54,57 @@ pub struct RegexAndDFA {
 pub struct SubstringDefinitionsJson {
     pub transitions: Vec<Vec<(usize, usize)>>,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnarkjsVerifyingKey {
+    pub protocol: String,
+    pub curve: String,
+    #[serde(rename = "nPublic")]
+    pub n_public: usize,
+    pub vk_alpha_1: Vec<String>,
+    pub vk_beta_2: Vec<Vec<String>>,
+    pub vk_gamma_2: Vec<Vec<String>>,
+    pub vk_delta_2: Vec<Vec<String>>,
+    pub vk_alphabeta_12: Vec<Vec<Vec<String>>>,
+    #[serde(rename = "IC")]
+    pub ic: Vec<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnarkjsProof {
+    pub pi_a: Vec<String>,
+    pub pi_b: Vec<Vec<String>>,
+    pub pi_c: Vec<String>,
+    pub protocol: String,
+    pub curve: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DFATable {
+    pub max_states: usize,
+    pub transitions: Vec<u8>,
+    pub accept: Vec<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Trace {
+    pub states: Vec<usize>,
+    pub stuck_at: Option<usize>,
+    pub accepted: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Disagreement {
+    pub regex: String,
+    pub input: Vec<u8>,
+    pub regex_crate: bool,
+    pub simulated: bool,
+    pub circuit: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Counterexample {
+    pub input: Vec<u8>,
+    pub accepted_by_left: bool,
+    pub accepted_by_right: bool,
+}
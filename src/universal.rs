@@ -0,0 +1,436 @@
+use crate::{
+    circuit::{alloc_input_vars, element_to_byte, PACKED_CHUNK_BYTES},
+    commitment::{hash, hash_gadget},
+    errors::CompilerError,
+    structs::{DFAGraph, DFAStateNode, DFATable},
+};
+use ark_crypto_primitives::sponge::Absorb;
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    alloc::AllocVar, boolean::Boolean, eq::EqGadget, fields::fp::FpVar, fields::FieldVar,
+    uint8::UInt8, ToBitsGadget,
+};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+/// Number of table columns per state, one for every byte value.
+pub const TABLE_WIDTH: usize = 256;
+
+/// Renumbers the states of `dfa` into a canonical order.
+///
+/// # Arguments
+///
+/// * `dfa` - The graph to normalize; the state with `state_id` 0 is the start state.
+///
+/// # Returns
+///
+/// A `Result` containing the renumbered `DFAGraph`, or a `CompilerError` if a transition
+/// points to a state that does not exist.
+///
+/// # Function Behavior
+///
+/// - Performs a Breadth-First Search from the start state, like `sort_and_rename_states`
+///   does for freshly compiled graphs, visiting the successors of a state in the order of
+///   the smallest byte leading to them.
+/// - Drops states that are unreachable from the start state.
+/// - Two graphs that differ only in how their states are numbered therefore normalize to
+///   the same graph, which makes tables and commitments derived from it canonical.
+pub fn canonicalize(dfa: &DFAGraph) -> Result<DFAGraph, CompilerError> {
+    let find_state = |state_id: usize| {
+        dfa.states
+            .iter()
+            .find(|s| s.state_id == state_id)
+            .ok_or_else(|| CompilerError::GraphError(format!("Unknown state {}", state_id)))
+    };
+
+    let mut sorted_states = Vec::new();
+    let mut visited = BTreeSet::new();
+    let mut queue = VecDeque::from([0]);
+
+    // BFS to sort states
+    while let Some(state_id) = queue.pop_front() {
+        if !visited.insert(state_id) {
+            continue;
+        }
+        let state = find_state(state_id)?;
+        let mut successors: Vec<_> = state
+            .transitions
+            .iter()
+            .map(|(&to_state, char_set)| (char_set.iter().next().copied(), to_state))
+            .collect();
+        successors.sort();
+        queue.extend(successors.into_iter().map(|(_, to_state)| to_state));
+        sorted_states.push(state);
+    }
+
+    // Rename states and update transitions
+    let state_map: BTreeMap<_, _> = sorted_states
+        .iter()
+        .enumerate()
+        .map(|(new_id, state)| (state.state_id, new_id))
+        .collect();
+    let states = sorted_states
+        .into_iter()
+        .enumerate()
+        .map(|(new_id, state)| {
+            let mut transitions: BTreeMap<usize, BTreeSet<u8>> = BTreeMap::new();
+            for (to_state, char_set) in &state.transitions {
+                transitions
+                    .entry(state_map[to_state])
+                    .or_default()
+                    .extend(char_set.iter().copied());
+            }
+            DFAStateNode {
+                state_type: state.state_type.clone(),
+                state_id: new_id,
+                transitions,
+            }
+        })
+        .collect();
+
+    Ok(DFAGraph { states })
+}
+
+/// Serializes a `DFAGraph` into the fixed-shape transition table of a universal circuit.
+///
+/// # Arguments
+///
+/// * `dfa` - The graph to serialize; the state with `state_id` 0 is the start state.
+/// * `max_states` - The number of table rows, at most 256 so that states fit in a byte.
+///
+/// # Returns
+///
+/// A `Result` containing the `DFATable`, or a `CompilerError` if the graph does not fit.
+///
+/// # Function Behavior
+///
+/// - Renumbers the states with `canonicalize` first, so the table only depends on the
+///   graph's structure and not on how its states happen to be numbered.
+/// - Row `s`, column `b` holds the state reached from `s` on byte `b`.
+/// - Byte 0 is padding, as in the generated circuits, so every row maps it to itself.
+/// - Missing transitions go to a dead state, numbered `n`, that loops on every byte and
+///   does not accept; rows past `n` are filled the same way. This needs `n < max_states`.
+pub fn dfa_to_table(dfa: &DFAGraph, max_states: usize) -> Result<DFATable, CompilerError> {
+    if max_states > TABLE_WIDTH {
+        return Err(CompilerError::GraphError(format!(
+            "A table holds at most {} states, got {}",
+            TABLE_WIDTH, max_states
+        )));
+    }
+    let dfa = canonicalize(dfa)?;
+    let dead_state = dfa.states.len();
+    if dead_state >= max_states {
+        return Err(CompilerError::GraphError(format!(
+            "DFA has {} states but the table only fits {} besides the dead state",
+            dfa.states.len(),
+            max_states - 1
+        )));
+    }
+
+    let mut table = DFATable::empty(max_states);
+    for s in 0..max_states {
+        table.transitions[s * TABLE_WIDTH] = s as u8;
+        for b in 1..TABLE_WIDTH {
+            table.transitions[s * TABLE_WIDTH + b] = dead_state as u8;
+        }
+    }
+
+    for (index, state) in dfa.states.iter().enumerate() {
+        let mut seen = BTreeSet::new();
+        for (&to_state, char_set) in &state.transitions {
+            for &c in char_set.iter().filter(|&&c| c != 0) {
+                if !seen.insert(c) {
+                    return Err(CompilerError::GraphError(format!(
+                        "State {} has several transitions on byte {}",
+                        index, c
+                    )));
+                }
+                table.transitions[index * TABLE_WIDTH + c as usize] = to_state as u8;
+            }
+        }
+        table.accept[index] = state.state_type == "accept";
+    }
+
+    Ok(table)
+}
+
+/// Computes the public commitment of a hidden regex, see `UniversalRegexCircuit::hidden`.
+///
+/// The same regex always yields the same commitment for a given `max_states` and `salt`,
+/// since the table is built from the canonical form of its graph. The salt must be random
+/// and kept secret: without it, anyone could recover the regex by committing to candidate
+/// patterns until one matches.
+pub fn hidden_regex_commitment<F: PrimeField + Absorb>(
+    dfa: &DFAGraph,
+    max_states: usize,
+    salt: F,
+) -> Result<F, CompilerError> {
+    Ok(table_commitment(&dfa_to_table(dfa, max_states)?, salt))
+}
+
+/// Computes the Poseidon commitment of a private input, the first public input of
+/// `UniversalRegexCircuit::hidden`.
+///
+/// The hash covers `salt` followed by the input, zero-padded to `max_len` bytes and packed
+/// into 31-byte chunks. As for the table, the salt keeps a low-entropy input from being
+/// recovered by hashing guesses.
+pub fn input_commitment<F: PrimeField + Absorb>(input: &[u8], max_len: usize, salt: F) -> F {
+    let mut bytes = input.to_vec();
+    bytes.resize(max_len, 0);
+    let mut elements = vec![salt];
+    elements.extend(bytes.chunks(PACKED_CHUNK_BYTES).map(F::from_le_bytes_mod_order));
+    hash(&elements)
+}
+
+impl DFATable {
+    /// A table of `max_states` rows that rejects every non-empty input.
+    ///
+    /// Useful for running the setup of a universal circuit, which only depends on its shape.
+    pub fn empty(max_states: usize) -> Self {
+        DFATable {
+            max_states,
+            transitions: vec![0; max_states * TABLE_WIDTH],
+            accept: vec![false; max_states],
+        }
+    }
+
+    /// The table's bytes in packing order: every transition, then one byte per accept flag.
+    fn bytes(&self) -> Vec<u8> {
+        self.transitions
+            .iter()
+            .copied()
+            .chain(self.accept.iter().map(|&a| a as u8))
+            .collect()
+    }
+}
+
+/// Packs the table into 31-byte field elements; these are the public inputs of `TableMode::Public`.
+pub fn table_chunks<F: PrimeField>(table: &DFATable) -> Vec<F> {
+    table
+        .bytes()
+        .chunks(PACKED_CHUNK_BYTES)
+        .map(F::from_le_bytes_mod_order)
+        .collect()
+}
+
+/// Computes the Poseidon commitment of the table; the public input of `TableMode::Committed`.
+///
+/// The hash covers the blinding `salt` and `max_states`, followed by the packed table chunks.
+pub fn table_commitment<F: PrimeField + Absorb>(table: &DFATable, salt: F) -> F {
+    let mut elements = vec![salt, F::from(table.max_states as u64)];
+    elements.extend(table_chunks::<F>(table));
+    hash(&elements)
+}
+
+/// How the transition table of a universal circuit is bound to its public inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableMode {
+    /// The packed table chunks are public inputs.
+    Public,
+    /// Only the Poseidon commitment of the table, blinded by `table_salt`, is a public input.
+    Committed,
+}
+
+/// A regex circuit of fixed shape `(max_states, max_len)` that reads its DFA from a table.
+///
+/// One setup serves every regex whose `DFAGraph` fits into `max_states - 1` states.
+/// The public inputs are the input bytes (see `alloc_input_vars`) followed by the table
+/// chunks or its commitment, depending on `table_mode`. With `private_input` the input
+/// bytes are witnesses exposed only through their `input_commitment`, and `packed_input`
+/// is ignored.
+#[derive(Clone)]
+pub struct UniversalRegexCircuit<F: PrimeField> {
+    pub table: DFATable,
+    pub table_mode: TableMode,
+    /// Private blinding of the table commitment; unused in `TableMode::Public`.
+    pub table_salt: F,
+    pub input: Vec<F>,
+    /// Private blinding of the input commitment; unused without `private_input`.
+    pub input_salt: F,
+    pub max_len: usize,
+    pub packed_input: bool,
+    pub private_input: bool,
+}
+
+impl<F: PrimeField> UniversalRegexCircuit<F> {
+    /// Creates a circuit proving that `input` matches `dfa` while keeping both private.
+    ///
+    /// The public inputs are the `input_commitment` of `input` under `input_salt`, then the
+    /// `hidden_regex_commitment` of `dfa` under `table_salt`. A verifier holding both learns
+    /// that the committed string matches the committed pattern but neither of them.
+    pub fn hidden(
+        dfa: &DFAGraph,
+        input: &[u8],
+        max_states: usize,
+        max_len: usize,
+        table_salt: F,
+        input_salt: F,
+    ) -> Result<Self, CompilerError> {
+        Ok(Self {
+            table: dfa_to_table(dfa, max_states)?,
+            table_mode: TableMode::Committed,
+            table_salt,
+            input: input.iter().map(|&b| F::from(b as u64)).collect(),
+            input_salt,
+            max_len,
+            packed_input: false,
+            private_input: true,
+        })
+    }
+}
+
+/// The transition entries and accept flags of an allocated table.
+type TableVars<F> = (Vec<FpVar<F>>, Vec<FpVar<F>>);
+
+/// Allocates the table as range-checked bytes and binds it to the public inputs.
+///
+/// Returns the transition entries and accept flags as field variables.
+fn alloc_table_vars<F: PrimeField + Absorb>(
+    cs: ConstraintSystemRef<F>,
+    table: &DFATable,
+    table_mode: TableMode,
+    salt: F,
+) -> Result<TableVars<F>, SynthesisError> {
+    let transition_vars = UInt8::new_witness_vec(cs.clone(), &table.transitions)?;
+    let accept_vars = table
+        .accept
+        .iter()
+        .map(|&a| Boolean::new_witness(cs.clone(), || Ok(a)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut table_bits = transition_vars.to_bits_le()?;
+    for accept in &accept_vars {
+        table_bits.push(accept.clone());
+        table_bits.extend(vec![Boolean::constant(false); 7]);
+    }
+    let packed_vars = table_bits
+        .chunks(PACKED_CHUNK_BYTES * 8)
+        .map(Boolean::le_bits_to_fp_var)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match table_mode {
+        TableMode::Public => {
+            for (packed_var, chunk) in packed_vars.iter().zip(table_chunks::<F>(table)) {
+                FpVar::new_input(cs.clone(), || Ok(chunk))?.enforce_equal(packed_var)?;
+            }
+        }
+        TableMode::Committed => {
+            let mut elements = vec![
+                FpVar::new_witness(cs.clone(), || Ok(salt))?,
+                FpVar::constant(F::from(table.max_states as u64)),
+            ];
+            elements.extend(packed_vars);
+            let commitment = hash_gadget(cs.clone(), &elements)?;
+            FpVar::new_input(cs.clone(), || Ok(table_commitment::<F>(table, salt)))?
+                .enforce_equal(&commitment)?;
+        }
+    }
+
+    let transitions = transition_vars
+        .iter()
+        .map(|byte| Boolean::le_bits_to_fp_var(&byte.to_bits_le()?))
+        .collect::<Result<Vec<_>, _>>()?;
+    let accept = accept_vars.into_iter().map(FpVar::from).collect();
+    Ok((transitions, accept))
+}
+
+/// Pads `input` to `max_len` and allocates every byte as a range-checked private witness.
+///
+/// The bytes are bound to the public `input_commitment` under `salt`, so the proof is about
+/// this input and not just any matching one. As in `alloc_input_vars`, an input longer than
+/// `max_len` or an element that is not a byte is unsatisfiable.
+fn alloc_private_input_vars<F: PrimeField + Absorb>(
+    cs: ConstraintSystemRef<F>,
+    input: &[F],
+    max_len: usize,
+    salt: F,
+) -> Result<Vec<FpVar<F>>, SynthesisError> {
+    if input.len() > max_len {
+        return Err(SynthesisError::Unsatisfiable);
+    }
+    let input_bytes = input
+        .iter()
+        .map(|v| element_to_byte(v).ok_or(SynthesisError::Unsatisfiable))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut padded_bytes = input_bytes.clone();
+    padded_bytes.resize(max_len, 0);
+    let input_byte_vars = UInt8::new_witness_vec(cs.clone(), &padded_bytes)?;
+
+    let mut elements = vec![FpVar::new_witness(cs.clone(), || Ok(salt))?];
+    for chunk_vars in input_byte_vars.chunks(PACKED_CHUNK_BYTES) {
+        elements.push(Boolean::le_bits_to_fp_var(&chunk_vars.to_bits_le()?)?);
+    }
+    let commitment = hash_gadget(cs.clone(), &elements)?;
+    FpVar::new_input(cs, || Ok(input_commitment::<F>(&input_bytes, max_len, salt)))?
+        .enforce_equal(&commitment)?;
+
+    input_byte_vars
+        .iter()
+        .map(|byte| Boolean::le_bits_to_fp_var(&byte.to_bits_le()?))
+        .collect()
+}
+
+/// Selects `values[index]` with one-hot `selectors` as `sum(selector * value)`.
+fn select_one_hot<F: PrimeField>(
+    selectors: &[Boolean<F>],
+    values: &[FpVar<F>],
+) -> Result<FpVar<F>, SynthesisError> {
+    let mut sum = FpVar::zero();
+    for (selector, value) in selectors.iter().zip(values) {
+        sum += FpVar::from(selector.clone()) * value;
+    }
+    Ok(sum)
+}
+
+/// Returns `[var == 0, var == 1, ..., var == n - 1]`.
+fn one_hot<F: PrimeField>(var: &FpVar<F>, n: usize) -> Result<Vec<Boolean<F>>, SynthesisError> {
+    (0..n)
+        .map(|i| var.is_eq(&FpVar::constant(F::from(i as u64))))
+        .collect()
+}
+
+impl<F: PrimeField + Absorb> ConstraintSynthesizer<F> for UniversalRegexCircuit<F> {
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let max_states = self.table.max_states;
+        let input_vars = if self.private_input {
+            alloc_private_input_vars(cs.clone(), &self.input, self.max_len, self.input_salt)?
+        } else {
+            alloc_input_vars(cs.clone(), &self.input, self.max_len, self.packed_input)?
+        };
+        let (transitions, accept) =
+            alloc_table_vars(cs.clone(), &self.table, self.table_mode, self.table_salt)?;
+
+        let mut valid = Boolean::constant(true);
+        let mut padding_started = Boolean::constant(false);
+        let mut current_state = FpVar::constant(F::from(0u64));
+        for current_input in input_vars.iter() {
+            let state_selectors = one_hot(&current_state, max_states)?;
+            let byte_selectors = one_hot(current_input, TABLE_WIDTH)?;
+
+            // Public input bytes are not range-checked; exactly one selector must be set
+            let mut selected = FpVar::zero();
+            for selector in &byte_selectors {
+                selected += FpVar::from(selector.clone());
+            }
+            selected.enforce_equal(&FpVar::one())?;
+
+            // Padding may only trail the input
+            let is_padded = &byte_selectors[0];
+            valid = valid.and(&padding_started.and(&is_padded.not())?.not())?;
+            padding_started = padding_started.or(is_padded)?;
+
+            // Select the column of the current byte in every row, then the current row
+            let mut column = Vec::with_capacity(max_states);
+            for row in transitions.chunks(TABLE_WIDTH) {
+                column.push(select_one_hot(&byte_selectors, row)?);
+            }
+            current_state = select_one_hot(&state_selectors, &column)?;
+        }
+
+        // Acceptance logic
+        let is_accepting = select_one_hot(&one_hot(&current_state, max_states)?, &accept)?;
+        is_accepting.enforce_equal(&FpVar::one())?;
+        valid.enforce_equal(&Boolean::constant(true))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,206 @@
+use crate::{
+    errors::CompilerError,
+    structs::{DFAGraph, RegexAndDFA},
+};
+use std::{collections::BTreeSet, fmt, str::FromStr};
+
+/// Text formats a `DFAGraph` can be rendered to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    Dot,
+    Mermaid,
+}
+
+impl GraphFormat {
+    /// Every supported format, in the order they are listed by the CLI.
+    pub const ALL: [GraphFormat; 2] = [GraphFormat::Dot, GraphFormat::Mermaid];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            GraphFormat::Dot => "dot",
+            GraphFormat::Mermaid => "mermaid",
+        }
+    }
+}
+
+impl fmt::Display for GraphFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for GraphFormat {
+    type Err = CompilerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        GraphFormat::ALL
+            .into_iter()
+            .find(|format| format.name() == s.to_ascii_lowercase())
+            .ok_or_else(|| CompilerError::GenericError(format!("Unsupported graph format: {}", s)))
+    }
+}
+
+impl DFAGraph {
+    /// Renders the graph in Graphviz DOT, see `render`.
+    pub fn to_dot(&self) -> String {
+        self.render(GraphFormat::Dot, &BTreeSet::new())
+    }
+
+    /// Renders the graph as a Mermaid flowchart, see `render`.
+    pub fn to_mermaid(&self) -> String {
+        self.render(GraphFormat::Mermaid, &BTreeSet::new())
+    }
+
+    /// Renders the graph in `format`.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The output format.
+    /// * `highlighted` - `(from, to)` state pairs whose transitions are drawn in red, such as
+    ///   the transitions of a revealed substring.
+    ///
+    /// # Function Behavior
+    ///
+    /// - Accept states are drawn as double circles and the start state 0 gets an incoming
+    ///   arrow.
+    /// - Every transition is labeled with its bytes collapsed into a character class such
+    ///   as `[a-zA-Z]`; see `byte_class`.
+    pub fn render(&self, format: GraphFormat, highlighted: &BTreeSet<(usize, usize)>) -> String {
+        match format {
+            GraphFormat::Dot => render_dot(self, highlighted),
+            GraphFormat::Mermaid => render_mermaid(self, highlighted),
+        }
+    }
+}
+
+impl RegexAndDFA {
+    /// Renders the graph in `format`, highlighting the transitions of every substring.
+    pub fn render(&self, format: GraphFormat) -> String {
+        let highlighted = self
+            .substrings
+            .substring_ranges
+            .iter()
+            .flatten()
+            .copied()
+            .collect();
+        self.dfa.render(format, &highlighted)
+    }
+}
+
+/// Collapses `bytes` into a readable label: a single character, or a class such as `[a-zA-Z]`.
+///
+/// Runs of three or more consecutive bytes become ranges. Non-printable bytes are written as
+/// `\xNN` and class metacharacters are escaped with a backslash.
+pub fn byte_class(bytes: &BTreeSet<u8>) -> String {
+    let mut runs: Vec<(u8, u8)> = vec![];
+    for &byte in bytes {
+        match runs.last_mut() {
+            Some((_, end)) if *end as u16 + 1 == byte as u16 => *end = byte,
+            _ => runs.push((byte, byte)),
+        }
+    }
+
+    match runs[..] {
+        [(b' ', b' ')] => return "[ ]".to_string(),
+        [(start, end)] if start == end => return escape_byte(start),
+        _ => {}
+    }
+
+    let mut label = "[".to_string();
+    for (start, end) in runs {
+        label.push_str(&escape_byte(start));
+        match end - start {
+            0 => {}
+            1 => label.push_str(&escape_byte(end)),
+            _ => {
+                label.push('-');
+                label.push_str(&escape_byte(end));
+            }
+        }
+    }
+    label.push(']');
+    label
+}
+
+fn escape_byte(byte: u8) -> String {
+    match byte {
+        b'[' | b']' | b'\\' | b'-' | b'^' => format!("\\{}", byte as char),
+        0x20..=0x7e => (byte as char).to_string(),
+        _ => format!("\\x{:02x}", byte),
+    }
+}
+
+fn render_dot(dfa: &DFAGraph, highlighted: &BTreeSet<(usize, usize)>) -> String {
+    let mut lines = vec![
+        "digraph DFA {".to_string(),
+        "  rankdir=LR;".to_string(),
+        "  node [shape=circle];".to_string(),
+        "  start [shape=point];".to_string(),
+        "  start -> 0;".to_string(),
+    ];
+
+    for state in &dfa.states {
+        if state.state_type == "accept" {
+            lines.push(format!("  {} [shape=doublecircle];", state.state_id));
+        }
+    }
+    for state in &dfa.states {
+        for (&to_state, char_set) in &state.transitions {
+            let label = byte_class(char_set).replace('\\', "\\\\").replace('"', "\\\"");
+            let style = if highlighted.contains(&(state.state_id, to_state)) {
+                ", color=red, fontcolor=red, penwidth=2"
+            } else {
+                ""
+            };
+            lines.push(format!(
+                "  {} -> {} [label=\"{}\"{}];",
+                state.state_id, to_state, label, style
+            ));
+        }
+    }
+
+    lines.push("}".to_string());
+    lines.join("\n")
+}
+
+fn render_mermaid(dfa: &DFAGraph, highlighted: &BTreeSet<(usize, usize)>) -> String {
+    let mut lines = vec![
+        "flowchart LR".to_string(),
+        "  start([start]) --> s0".to_string(),
+    ];
+
+    for state in &dfa.states {
+        if state.state_type == "accept" {
+            lines.push(format!("  s{}((({})))", state.state_id, state.state_id));
+        } else {
+            lines.push(format!("  s{}(({}))", state.state_id, state.state_id));
+        }
+    }
+
+    // Mermaid styles links by their index, counting the arrow from `start` as link 0
+    let mut highlighted_links = vec![];
+    let mut link = 1;
+    for state in &dfa.states {
+        for (&to_state, char_set) in &state.transitions {
+            // Entity codes keep Mermaid from reading quotes, tags and `#` in the label
+            let label = byte_class(char_set)
+                .replace('#', "#35;")
+                .replace('"', "#quot;")
+                .replace('<', "#lt;")
+                .replace('>', "#gt;");
+            lines.push(format!("  s{} -->|\"{}\"| s{}", state.state_id, label, to_state));
+            if highlighted.contains(&(state.state_id, to_state)) {
+                highlighted_links.push(link.to_string());
+            }
+            link += 1;
+        }
+    }
+
+    if !highlighted_links.is_empty() {
+        lines.push(format!(
+            "  linkStyle {} stroke:red,stroke-width:2px",
+            highlighted_links.join(",")
+        ));
+    }
+    lines.join("\n")
+}
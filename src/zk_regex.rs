@@ -0,0 +1,231 @@
+use crate::{
+    errors::CompilerError,
+    regex::process_state_edges,
+    structs::{DFAGraph, DFAStateNode, RegexAndDFA, SubstringDefinitions},
+};
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Imports the JSON output of the zk-regex compiler as a `RegexAndDFA`.
+///
+/// # Arguments
+///
+/// * `json` - A zk-regex output, or only its graph.
+///
+/// # Returns
+///
+/// A `Result` containing the validated `RegexAndDFA`, or a `CompilerError` if the JSON
+/// has none of the supported layouts, naming the field that does not fit, or describes an
+/// invalid graph.
+///
+/// # Function Behavior
+///
+/// - The graph is read from `dfa` or `graph`, as an array of states or under `states`.
+/// - A state takes its id from `state_id` or `state`, defaulting to its position, its type
+///   from `state_type` or `type`, and its transitions from `transitions` or `edges`.
+/// - A transition maps either a target state to its bytes, or a byte range label such as
+///   `a-z` to its target state; labels are parsed like the edges of compiled regexes.
+/// - Byte 0 is dropped from every transition since circuits reserve it for padding.
+/// - Substrings are read as `SubstringDefinitions`, as `SubstringDefinitionsJson`, or as
+///   a bare list of transition lists, and must name transitions of the graph.
+/// - `has_end_anchor` defaults to whether the regex ends with `$`.
+pub fn import_zk_regex_json(json: &str) -> Result<RegexAndDFA, CompilerError> {
+    let document: Value = serde_json::from_str(json)?;
+    // A whole output holds the graph under `dfa` or `graph`, otherwise it is the graph
+    let output = document
+        .as_object()
+        .filter(|object| field(object, &["dfa", "graph"]).is_some());
+    let Some(output) = output else {
+        let dfa = parse_graph(&document, "graph")?;
+        return Ok(RegexAndDFA {
+            regex_pattern: String::new(),
+            has_end_anchor: false,
+            dfa,
+            substrings: SubstringDefinitions {
+                substring_ranges: vec![],
+                substring_boundaries: None,
+            },
+        });
+    };
+
+    let (name, graph) = field(output, &["dfa", "graph"]).unwrap();
+    let dfa = parse_graph(graph, name)?;
+    let substring_ranges = match field(output, &["substrings"]) {
+        Some((name, substrings)) => parse_substrings(substrings, name)?,
+        None => SubstringDefinitions {
+            substring_ranges: vec![],
+            substring_boundaries: None,
+        },
+    };
+    check_substrings(&dfa, &substring_ranges)?;
+
+    let regex_pattern = match field(output, &["regex_pattern", "regex"]) {
+        Some((name, regex)) => parse::<Option<String>>(regex, name)?.unwrap_or_default(),
+        None => String::new(),
+    };
+    let has_end_anchor = match field(output, &["has_end_anchor"]) {
+        Some((name, has_end_anchor)) => parse::<Option<bool>>(has_end_anchor, name)?,
+        None => None,
+    };
+    Ok(RegexAndDFA {
+        has_end_anchor: has_end_anchor.unwrap_or_else(|| regex_pattern.ends_with('$')),
+        regex_pattern,
+        dfa,
+        substrings: substring_ranges,
+    })
+}
+
+/// Returns the first of the `names` aliases present in `object`, with the name it has.
+fn field<'a>(
+    object: &'a Map<String, Value>,
+    names: &[&'static str],
+) -> Option<(&'static str, &'a Value)> {
+    names
+        .iter()
+        .find_map(|&name| object.get(name).map(|value| (name, value)))
+}
+
+/// Returns the error for the value at `path`, such as `dfa.states[2].edges["1"]`.
+fn invalid(path: &str, message: impl std::fmt::Display) -> CompilerError {
+    CompilerError::ParseError(format!("Invalid zk-regex JSON at {}: {}", path, message))
+}
+
+/// Deserializes the value at `path`, naming `path` on failure.
+fn parse<T: DeserializeOwned>(value: &Value, path: &str) -> Result<T, CompilerError> {
+    T::deserialize(value).map_err(|err| invalid(path, err))
+}
+
+/// Parses a graph at `path`: an array of states, or an object holding them under `states`.
+fn parse_graph(graph: &Value, path: &str) -> Result<DFAGraph, CompilerError> {
+    let (path, states) = match graph {
+        Value::Object(object) => match object.get("states") {
+            Some(states) => (format!("{}.states", path), states),
+            None => return Err(invalid(path, "expected an array of states or `states`")),
+        },
+        states => (path.to_string(), states),
+    };
+    let Value::Array(states) = states else {
+        return Err(invalid(&path, "expected an array of states"));
+    };
+
+    let dfa = DFAGraph {
+        states: states
+            .iter()
+            .enumerate()
+            .map(|(index, state)| convert_state(index, state, &format!("{}[{}]", path, index)))
+            .collect::<Result<_, _>>()?,
+    };
+    dfa.validate()?;
+    Ok(dfa)
+}
+
+/// Converts the zk-regex state at position `index` and `path` into a `DFAStateNode`.
+fn convert_state(index: usize, state: &Value, path: &str) -> Result<DFAStateNode, CompilerError> {
+    let Value::Object(state) = state else {
+        return Err(invalid(path, "expected a state object"));
+    };
+    let state_id = match field(state, &["state_id", "state"]) {
+        Some((name, state_id)) => parse(state_id, &format!("{}.{}", path, name))?,
+        None => index,
+    };
+    let state_type = match field(state, &["state_type", "type"]) {
+        Some((name, state_type)) => parse(state_type, &format!("{}.{}", path, name))?,
+        None => String::new(),
+    };
+
+    let mut labels = BTreeMap::new();
+    let mut transitions: BTreeMap<usize, BTreeSet<u8>> = BTreeMap::new();
+    if let Some((name, edges)) = field(state, &["transitions", "edges"]) {
+        let edges_path = format!("{}.{}", path, name);
+        let Value::Object(edges) = edges else {
+            return Err(invalid(&edges_path, "expected an object of transitions"));
+        };
+        for (key, target) in edges {
+            let target_path = format!("{}[{:?}]", edges_path, key);
+            match target {
+                // The key is a byte range label such as `a-z` or `\n`, the value its target
+                Value::Number(_) => {
+                    labels.insert(key.clone(), parse::<usize>(target, &target_path)?);
+                }
+                // The key is the target state, the value its bytes
+                Value::Array(_) => {
+                    let to_state = key.parse::<usize>().map_err(|_| {
+                        invalid(&target_path, format!("invalid target state \"{}\"", key))
+                    })?;
+                    let bytes = parse::<Vec<u8>>(target, &target_path)?;
+                    transitions.entry(to_state).or_default().extend(bytes);
+                }
+                _ => {
+                    return Err(invalid(
+                        &target_path,
+                        "expected a target state or a list of bytes",
+                    ))
+                }
+            }
+        }
+    }
+    for (to_state, char_set) in process_state_edges(&labels)? {
+        transitions.entry(to_state).or_default().extend(char_set);
+    }
+
+    for char_set in transitions.values_mut() {
+        char_set.remove(&0);
+    }
+    transitions.retain(|_, char_set| !char_set.is_empty());
+
+    Ok(DFAStateNode {
+        state_type,
+        state_id,
+        transitions,
+    })
+}
+
+/// Parses the substrings at `path`: `SubstringDefinitions`, `SubstringDefinitionsJson`, or
+/// a bare list of transition lists.
+fn parse_substrings(substrings: &Value, path: &str) -> Result<SubstringDefinitions, CompilerError> {
+    let transitions = match substrings {
+        Value::Null => vec![],
+        Value::Object(object) if object.contains_key("substring_ranges") => {
+            return parse(substrings, path)
+        }
+        Value::Object(object) => match object.get("transitions") {
+            Some(transitions) => {
+                parse::<Vec<Vec<(usize, usize)>>>(transitions, &format!("{}.transitions", path))?
+            }
+            None => {
+                return Err(invalid(path, "expected `substring_ranges` or `transitions`"))
+            }
+        },
+        transitions => parse::<Vec<Vec<(usize, usize)>>>(transitions, path)?,
+    };
+    Ok(SubstringDefinitions {
+        substring_ranges: transitions
+            .into_iter()
+            .map(|ranges| ranges.into_iter().collect())
+            .collect(),
+        substring_boundaries: None,
+    })
+}
+
+/// Checks that every substring transition is a transition of `dfa`.
+fn check_substrings(
+    dfa: &DFAGraph,
+    substrings: &SubstringDefinitions,
+) -> Result<(), CompilerError> {
+    for (index, ranges) in substrings.substring_ranges.iter().enumerate() {
+        for &(from_state, to_state) in ranges {
+            let exists = dfa
+                .states
+                .iter()
+                .any(|s| s.state_id == from_state && s.transitions.contains_key(&to_state));
+            if !exists {
+                return Err(CompilerError::GraphError(format!(
+                    "Substring {} uses transition {} -> {}, which is not in the graph",
+                    index, from_state, to_state
+                )));
+            }
+        }
+    }
+    Ok(())
+}
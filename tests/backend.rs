@@ -0,0 +1,168 @@
+mod common;
+
+use arkworks_regex_circuit::{
+    arkworks::gen_arkworks_allstr,
+    backend::{
+        apply_match_mode, backend_by_name, backends, BackendOptions, CircuitBackend,
+        InputEncoding, MatchMode,
+    },
+    errors::CompilerError,
+    structs::DFAGraph,
+};
+use common::dfa;
+use std::collections::BTreeSet;
+
+/// A third-party backend listing the transitions of the graph.
+struct TransitionList;
+
+impl CircuitBackend for TransitionList {
+    fn name(&self) -> &'static str {
+        "transitions"
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "txt"
+    }
+
+    fn generate(
+        &self,
+        dfa_graph: &DFAGraph,
+        _regex_str: &str,
+        options: &BackendOptions,
+    ) -> Result<String, CompilerError> {
+        let dfa_graph = apply_match_mode(dfa_graph, options.match_mode);
+        Ok(dfa_graph
+            .states
+            .iter()
+            .flat_map(|s| s.transitions.keys().map(move |to| format!("{}->{}", s.state_id, to)))
+            .collect::<Vec<_>>()
+            .join(" "))
+    }
+}
+
+#[test]
+fn backends_are_found_by_name() {
+    let names: Vec<_> = backends().iter().map(|backend| backend.name()).collect();
+    assert_eq!(names, ["arkworks", "circom", "noir"]);
+    assert_eq!(backend_by_name("Circom").unwrap().file_extension(), "circom");
+    assert!(matches!(backend_by_name("halo2"), Err(CompilerError::CodegenError(_))));
+}
+
+#[test]
+fn arkworks_backend_wraps_the_generator() {
+    let graph = dfa("a[bc]+d");
+    let options = BackendOptions { encoding: InputEncoding::Packed, ..Default::default() };
+    let code = backend_by_name("arkworks").unwrap().generate(&graph, "a[bc]+d", &options).unwrap();
+    assert_eq!(code, gen_arkworks_allstr(&graph, "RegexCircuit", "a[bc]+d", None, true).unwrap());
+
+    let options = BackendOptions { max_len: Some(64), ..Default::default() };
+    let code = backend_by_name("arkworks").unwrap().generate(&graph, "a[bc]+d", &options).unwrap();
+    assert!(code.contains("pub const MAX_LEN: usize = 64;"));
+}
+
+#[test]
+fn entry_points_use_max_len() {
+    let graph = dfa("a[bc]+d");
+    let options = BackendOptions {
+        struct_name: "ABCD".to_string(),
+        max_len: Some(32),
+        reveal: vec![BTreeSet::from([(1, 2), (2, 2)])],
+        ..Default::default()
+    };
+
+    let circom = backend_by_name("circom").unwrap().generate(&graph, "a[bc]+d", &options).unwrap();
+    assert!(circom.contains("template ABCD(maxLen) {"));
+    assert!(circom.contains("signal output reveal0[maxLen];"));
+    assert!(circom.ends_with("component main {public [in]} = ABCD(32);\n"));
+
+    let noir = backend_by_name("noir").unwrap().generate(&graph, "a[bc]+d", &options).unwrap();
+    assert!(noir.contains("pub fn abcd_match<let N: u32>(input: [u8; N]) -> bool {"));
+    assert!(noir.ends_with(
+        "fn main(input: pub [u8; 32]) -> pub [[u8; 32]; 1] {\n    \
+         assert(abcd_match(input));\n    abcd_reveal(input)\n}\n"
+    ));
+
+    let options = BackendOptions { max_len: Some(32), ..Default::default() };
+    let noir = backend_by_name("noir").unwrap().generate(&graph, "a[bc]+d", &options).unwrap();
+    let main = "fn main(input: pub [u8; 32]) {\n    assert(regex_match(input));\n}\n";
+    assert!(noir.ends_with(main));
+}
+
+#[test]
+fn unsupported_options_are_rejected() {
+    let graph = dfa("ab");
+    let rejects = |backend: &str, options: &BackendOptions| {
+        matches!(
+            backend_by_name(backend).unwrap().generate(&graph, "ab", options),
+            Err(CompilerError::CodegenError(_))
+        )
+    };
+    let reveal = BackendOptions { reveal: vec![BTreeSet::from([(0, 1)])], ..Default::default() };
+    assert!(rejects("arkworks", &reveal));
+
+    let packed = BackendOptions { encoding: InputEncoding::Packed, ..Default::default() };
+    assert!(rejects("circom", &packed));
+    assert!(rejects("noir", &packed));
+}
+
+#[test]
+fn circom_template_names_are_validated() {
+    let graph = dfa("ab");
+    let circom = backend_by_name("circom").unwrap();
+    for name in ["Regex Circuit", "X(maxLen)", "1ab", "", "signal", "a-b", "__"] {
+        let options = BackendOptions { struct_name: name.to_string(), ..Default::default() };
+        assert!(
+            matches!(circom.generate(&graph, "ab", &options), Err(CompilerError::CodegenError(_))),
+            "{:?}",
+            name
+        );
+    }
+    for name in ["RegexCircuit", "_Ab1", "$x"] {
+        let options = BackendOptions { struct_name: name.to_string(), ..Default::default() };
+        assert!(circom.generate(&graph, "ab", &options).is_ok(), "{:?}", name);
+    }
+}
+
+#[test]
+fn noir_function_names_follow_the_struct_name() {
+    let graph = dfa("ab");
+    let noir = backend_by_name("noir").unwrap();
+    for (name, function) in [
+        ("RegexCircuit", "regex_match"),
+        ("EmailDomain", "email_domain_match"),
+        ("Sha256Header", "sha256_header_match"),
+        ("Circuit", "circuit_match"),
+        ("snake_case", "snake_case_match"),
+    ] {
+        let options = BackendOptions { struct_name: name.to_string(), ..Default::default() };
+        let code = noir.generate(&graph, "ab", &options).unwrap();
+        assert!(code.contains(&format!("pub fn {}<let N: u32>", function)), "{:?}", name);
+    }
+    for name in ["Regex Circuit", "1ab", "", "a-b", "$x"] {
+        let options = BackendOptions { struct_name: name.to_string(), ..Default::default() };
+        assert!(
+            matches!(noir.generate(&graph, "ab", &options), Err(CompilerError::CodegenError(_))),
+            "{:?}",
+            name
+        );
+    }
+}
+
+#[test]
+fn prefix_mode_ignores_what_follows_a_match() {
+    let graph = apply_match_mode(&dfa("a[bc]+d"), MatchMode::Prefix);
+    assert!(graph.run(b"abcd").accepted);
+    assert!(graph.run(b"abdxyz").accepted);
+    assert!(!graph.run(b"xabd").accepted);
+    assert!(!graph.run(b"abc").accepted);
+    assert!(!graph.run(b"abd\0x").accepted);
+    assert_eq!(apply_match_mode(&dfa("ab"), MatchMode::Full), dfa("ab"));
+}
+
+#[test]
+fn third_party_backends_plug_in() {
+    let backend: Box<dyn CircuitBackend> = Box::new(TransitionList);
+    let options = BackendOptions { match_mode: MatchMode::Prefix, ..Default::default() };
+    assert_eq!(backend.generate(&dfa("ab"), "ab", &Default::default()).unwrap(), "0->1 1->2");
+    assert_eq!(backend.generate(&dfa("ab"), "ab", &options).unwrap(), "0->1 1->2 2->3 3->3");
+}
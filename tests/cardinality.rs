@@ -0,0 +1,100 @@
+mod common;
+
+use arkworks_regex_circuit::{
+    cardinality::log2,
+    errors::CompilerError,
+    structs::DFAGraph,
+};
+use common::dfa;
+use num_bigint::BigUint;
+use regex::bytes::Regex;
+use std::collections::BTreeSet;
+
+/// The transitions `input` takes at the byte offsets in `offsets`.
+fn transitions_at(graph: &DFAGraph, input: &[u8], offsets: &[usize]) -> BTreeSet<(usize, usize)> {
+    let states = graph.run(input).states;
+    offsets.iter().map(|&i| (states[i], states[i + 1])).collect()
+}
+
+#[test]
+fn counts_agree_with_brute_force() {
+    for regex in [r"a[bc]+d", r"(ab|cd)*e", r"[^a]b", r"x?", r"(?s:.)", r"[a-z]{2}"] {
+        let expected = Regex::new(&format!("^(?:{})$", regex)).unwrap();
+        let mut brute_force = vec![0u64; 3];
+        brute_force[0] = expected.is_match(b"") as u64;
+        for first in 1..=u8::MAX {
+            brute_force[1] += expected.is_match(&[first]) as u64;
+            for second in 1..=u8::MAX {
+                brute_force[2] += expected.is_match(&[first, second]) as u64;
+            }
+        }
+
+        let size = dfa(regex).count_accepted(2);
+        let counts: Vec<BigUint> = brute_force.into_iter().map(BigUint::from).collect();
+        assert_eq!(size.counts, counts, "{}", regex);
+    }
+}
+
+#[test]
+fn counts_grow_with_length() {
+    let size = dfa(r"a[bc]+d").count_accepted(6);
+    let counts: Vec<BigUint> = [0u32, 0, 0, 2, 4, 8, 16].into_iter().map(BigUint::from).collect();
+    assert_eq!(size.counts, counts);
+    assert_eq!(size.total(), BigUint::from(30u32));
+
+    // 26^100 strings overflow every primitive integer
+    let size = dfa(r"[a-z]*").count_accepted(100);
+    assert_eq!(size.counts[100], BigUint::from(26u32).pow(100));
+    assert_eq!(size.total(), (BigUint::from(26u32).pow(101) - 1u32) / 25u32);
+    assert!((log2(&size.counts[100]) - 100.0 * 26f64.log2()).abs() < 1e-9);
+    assert!((size.log2_total() - 100.0 * 26f64.log2() - (26f64 / 25.0).log2()).abs() < 1e-9);
+}
+
+#[test]
+fn log2_handles_large_and_zero_counts() {
+    assert_eq!(log2(&BigUint::from(1024u32)), 10.0);
+    assert_eq!(log2(&(BigUint::from(1u32) << 300u32)), 300.0);
+    assert_eq!(log2(&BigUint::default()), f64::NEG_INFINITY);
+}
+
+#[test]
+fn revealing_nothing_discloses_nothing() {
+    let graph = dfa(r"[a-z]{2}[0-9]{2}");
+    let leakage = graph.leakage(b"ab12", 6, &[]).unwrap();
+    assert_eq!(leakage.language_size, BigUint::from(67600u32));
+    assert_eq!(leakage.consistent, leakage.language_size);
+    assert_eq!(leakage.disclosed_bits(), 0.0);
+    assert!(!leakage.discloses_input());
+}
+
+#[test]
+fn revealed_substrings_shrink_the_count() {
+    let graph = dfa(r"[a-z]{2}[0-9]{2}");
+    let digits = transitions_at(&graph, b"ab12", &[2, 3]);
+    let leakage = graph.leakage(b"ab12", 6, std::slice::from_ref(&digits)).unwrap();
+    // Only the two letters are left to guess
+    assert_eq!(leakage.consistent, BigUint::from(26u32 * 26));
+    assert!((leakage.remaining_bits() - 2.0 * 26f64.log2()).abs() < 1e-9);
+    assert!((leakage.disclosed_bits() - 2.0 * 10f64.log2()).abs() < 1e-9);
+
+    let letters = transitions_at(&graph, b"ab12", &[0, 1]);
+    let leakage = graph.leakage(b"ab12", 6, &[letters, digits]).unwrap();
+    assert!(leakage.discloses_input());
+    assert_eq!(leakage.remaining_bits(), 0.0);
+}
+
+#[test]
+fn revealed_rows_follow_the_circuit_outputs() {
+    let graph = dfa(r"a[bc]+d");
+    let middle = [transitions_at(&graph, b"abcd", &[1, 2])];
+    let revealed = graph.reveal(b"acbd", 6, &middle);
+    assert_eq!(revealed, vec![b"\0cb\0\0\0".to_vec()]);
+
+    // Revealing [bc]+ fixes the whole input, including its length
+    assert_eq!(graph.count_consistent(6, &middle, &revealed).unwrap(), BigUint::from(1u32));
+    assert!(matches!(
+        graph.count_consistent(6, &middle, &[b"\0cb".to_vec()]),
+        Err(CompilerError::GenericError(_))
+    ));
+    assert!(matches!(graph.leakage(b"abxd", 6, &middle), Err(CompilerError::InputRejected(_))));
+}
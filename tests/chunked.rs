@@ -0,0 +1,163 @@
+mod common;
+
+use ark_bn254::{Bn254, Fr};
+use ark_ff::Zero;
+use ark_groth16::Groth16;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystem};
+use ark_snark::SNARK;
+use ark_std::rand::{rngs::StdRng, SeedableRng};
+use arkworks_regex_circuit::chunked::{
+    chunk_commitment, chunk_input_commitment, prove_chunks, setup_chunks, verify_chunks,
+    ChunkCircuit, ChunkProof,
+};
+use common::{dfa, salt};
+
+const CHUNK_LEN: usize = 8;
+
+#[test]
+fn chained_chunks_verify() {
+    let graph = dfa(r"^[a-z]+(,[a-z]+)*;$");
+    let input = b"alpha,beta,gamma,delta;";
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let (pk, vk) = setup_chunks::<Fr, Groth16<Bn254>, _>(&graph, CHUNK_LEN, &mut rng).unwrap();
+    let mut proofs =
+        prove_chunks::<Fr, Groth16<Bn254>, _>(&pk, &graph, input, CHUNK_LEN, salt(), &mut rng)
+            .unwrap();
+    let commitment = chunk_input_commitment(input, CHUNK_LEN, salt());
+
+    assert_eq!(proofs.len(), 3);
+    assert_eq!(
+        proofs[1].commitment,
+        chunk_commitment(&input[8..16], CHUNK_LEN, proofs[0].commitment, salt())
+    );
+    assert_eq!(proofs[2].commitment, commitment);
+    assert!(verify_chunks::<Fr, Groth16<Bn254>>(&vk, &graph, &proofs, commitment).unwrap());
+
+    // The chain only verifies against the commitment of the proven input
+    let other = chunk_input_commitment(b"alpha,beta,gamma,delts;", CHUNK_LEN, salt());
+    assert!(!verify_chunks::<Fr, Groth16<Bn254>>(&vk, &graph, &proofs, other).unwrap());
+
+    // Dropping the last chunk leaves the chain in a non-accepting state
+    let last = proofs.pop().unwrap();
+    assert!(!verify_chunks::<Fr, Groth16<Bn254>>(&vk, &graph, &proofs, commitment).unwrap());
+
+    // Chunks out of order do not agree on their handoff states
+    proofs.insert(0, last);
+    assert!(!verify_chunks::<Fr, Groth16<Bn254>>(&vk, &graph, &proofs, commitment).unwrap());
+}
+
+#[test]
+fn chunks_of_another_input_cannot_be_spliced_in() {
+    let graph = dfa(r"^[a-z]+(,[a-z]+)*;$");
+    let input = b"alpha,beta,gamma,delta;";
+    let other = b"alpha,bxxx,gamma,delta;";
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let (pk, vk) = setup_chunks::<Fr, Groth16<Bn254>, _>(&graph, CHUNK_LEN, &mut rng).unwrap();
+    let mut proofs =
+        prove_chunks::<Fr, Groth16<Bn254>, _>(&pk, &graph, input, CHUNK_LEN, salt(), &mut rng)
+            .unwrap();
+    let other_proofs =
+        prove_chunks::<Fr, Groth16<Bn254>, _>(&pk, &graph, other, CHUNK_LEN, salt(), &mut rng)
+            .unwrap();
+
+    // The middle chunks hand off the same states, but the next chunk is chained to the
+    // commitment of the original one
+    let spliced = other_proofs.into_iter().nth(1).unwrap();
+    assert_eq!(
+        (spliced.start_state, spliced.end_state),
+        (proofs[1].start_state, proofs[1].end_state)
+    );
+    proofs[1] = spliced;
+    for commitment in [
+        chunk_input_commitment(input, CHUNK_LEN, salt()),
+        chunk_input_commitment(other, CHUNK_LEN, salt()),
+    ] {
+        assert!(!verify_chunks::<Fr, Groth16<Bn254>>(&vk, &graph, &proofs, commitment).unwrap());
+    }
+}
+
+#[test]
+fn padding_is_only_allowed_in_the_last_chunk() {
+    let graph = dfa(r"^a[bc]+d$");
+    let mut rng = StdRng::seed_from_u64(0);
+    let (pk, vk) = setup_chunks::<Fr, Groth16<Bn254>, _>(&graph, CHUNK_LEN, &mut rng).unwrap();
+
+    // "ab" padded to a full chunk followed by "cd" runs "ab\0...\0cd" through the graph
+    let first = ChunkCircuit::<Fr>::new(graph.clone(), b"ab", CHUNK_LEN, 0, Fr::zero(), salt())
+        .unwrap();
+    assert!(first.ends_in_padding);
+    let second = ChunkCircuit::<Fr>::new(
+        graph.clone(),
+        b"cd",
+        CHUNK_LEN,
+        first.end_state,
+        first.commitment,
+        salt(),
+    )
+    .unwrap();
+    let proofs = [first, second]
+        .into_iter()
+        .map(|circuit| ChunkProof::<Fr, Groth16<Bn254>> {
+            start_state: circuit.start_state,
+            end_state: circuit.end_state,
+            ends_in_padding: circuit.ends_in_padding,
+            commitment: circuit.commitment,
+            proof: Groth16::<Bn254>::prove(&pk, circuit, &mut rng).unwrap(),
+        })
+        .collect::<Vec<_>>();
+    let commitment = proofs[1].commitment;
+    assert!(!verify_chunks::<Fr, Groth16<Bn254>>(&vk, &graph, &proofs, commitment).unwrap());
+
+    // A chunk that ends in padding cannot claim otherwise
+    let mut circuit =
+        ChunkCircuit::<Fr>::new(graph, b"ab", CHUNK_LEN, 0, Fr::zero(), salt()).unwrap();
+    circuit.ends_in_padding = false;
+    let cs = ConstraintSystem::<Fr>::new_ref();
+    circuit.generate_constraints(cs.clone()).unwrap();
+    assert!(!cs.is_satisfied().unwrap());
+}
+
+#[test]
+fn chunk_commitments_are_blinded_by_their_salt() {
+    let chunk = b"abcd";
+    assert_ne!(
+        chunk_commitment(chunk, CHUNK_LEN, Fr::zero(), salt()),
+        chunk_commitment(chunk, CHUNK_LEN, Fr::zero(), salt() + Fr::from(1u64))
+    );
+}
+
+#[test]
+fn chunk_circuit_binds_its_end_state() {
+    let graph = dfa(r"^a[bc]+d$");
+    let mut circuit =
+        ChunkCircuit::<Fr>::new(graph, b"abc", CHUNK_LEN, 0, Fr::zero(), salt()).unwrap();
+    let cs = ConstraintSystem::<Fr>::new_ref();
+    circuit.clone().generate_constraints(cs.clone()).unwrap();
+    assert!(cs.is_satisfied().unwrap());
+    assert_eq!(cs.num_instance_variables(), 6);
+
+    circuit.end_state = 0;
+    let cs = ConstraintSystem::<Fr>::new_ref();
+    circuit.generate_constraints(cs.clone()).unwrap();
+    assert!(!cs.is_satisfied().unwrap());
+}
+
+#[test]
+fn chunks_that_leave_the_graph_are_rejected() {
+    let graph = dfa(r"^a[bc]+d$");
+    let new = |chunk: &[u8]| {
+        ChunkCircuit::<Fr>::new(graph.clone(), chunk, CHUNK_LEN, 0, Fr::zero(), salt())
+    };
+    assert!(new(b"ax").is_err());
+    assert!(new(b"abcdabcda").is_err());
+}
+
+#[test]
+fn empty_chunks_are_rejected() {
+    let graph = dfa(r"^a[bc]+d$");
+    let mut rng = StdRng::seed_from_u64(0);
+    assert!(setup_chunks::<Fr, Groth16<Bn254>, _>(&graph, 0, &mut rng).is_err());
+    assert!(ChunkCircuit::<Fr>::new(graph, b"", 0, 0, Fr::zero(), salt()).is_err());
+}
@@ -0,0 +1,376 @@
+//! Snapshot and semantic checks of the templates emitted by `gen_circom_allstr`.
+//!
+//! circom is not needed to run these tests: `evaluate` interprets the small subset of circom
+//! the generator emits, one statement per line, computing every `<==` and checking every
+//! `===`. Run with `UPDATE_SNAPSHOTS=1` to rewrite the snapshots.
+//!
+//! The ignored `circom_compiles_and_runs_generated_templates` checks the templates with the
+//! real toolchain instead. It needs `circom` and `node` on the `PATH` and `CIRCOMLIB_PATH`
+//! set to a directory holding `circomlib`, such as a `node_modules` directory; run it with
+//! `cargo test --test circom -- --ignored`.
+
+use arkworks_regex_circuit::{
+    circom::gen_circom_allstr,
+    regex::create_regex_and_dfa_from_str_and_defs,
+    structs::{DFAGraph, SubstringDefinitionsJson},
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    iter::Peekable,
+    path::Path,
+    process::Command,
+    vec::IntoIter,
+};
+
+const MAX_LEN: usize = 12;
+
+/// `(snapshot path, regex, substring transitions, inputs)` of a checked template.
+type Snapshot = (&'static str, &'static str, &'static [(usize, usize)], &'static [&'static str]);
+
+const SNAPSHOTS: &[Snapshot] = &[
+    (
+        "tests/snapshots/a_bc_d.circom",
+        r"a[bc]+d",
+        &[(1, 2), (2, 2)],
+        &["abcd", "acbcbd", "ad", "abxd", "ab\0cd", ""],
+    ),
+    (
+        "tests/snapshots/gmail.circom",
+        r"^[a-zA-Z]+[0-9]*@gmail\.com$",
+        &[],
+        &["b7@gmail.com", "b@gmail.co", "7b@gmail.com"],
+    ),
+    (
+        "tests/snapshots/alternation_star.circom",
+        r"(ab|cd)*e",
+        &[(0, 1), (1, 2)],
+        &["e", "abcde", "abe", "ace", "\0e"],
+    ),
+];
+
+type Signals = BTreeMap<String, i128>;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(i128),
+    Symbol(char),
+}
+
+fn tokenize(expression: &str) -> Peekable<IntoIter<Token>> {
+    let mut tokens = vec![];
+    let mut chars = expression.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c.is_ascii_digit() {
+            let mut number = 0;
+            while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+                number = number * 10 + digit as i128;
+                chars.next();
+            }
+            tokens.push(Token::Number(number));
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let mut ident = String::new();
+            while let Some(&c) = chars.peek().filter(|c| c.is_ascii_alphanumeric() || **c == '_') {
+                ident.push(c);
+                chars.next();
+            }
+            tokens.push(Token::Ident(ident));
+        } else {
+            tokens.push(Token::Symbol(c));
+            chars.next();
+        }
+    }
+    tokens.into_iter().peekable()
+}
+
+struct Scope<'a> {
+    signals: &'a Signals,
+    i: i128,
+    max_len: i128,
+}
+
+impl Scope<'_> {
+    fn expect(&self, tokens: &mut Peekable<IntoIter<Token>>, symbol: char) -> Result<(), String> {
+        match tokens.next() {
+            Some(Token::Symbol(c)) if c == symbol => Ok(()),
+            other => Err(format!("expected {:?}, got {:?}", symbol, other)),
+        }
+    }
+
+    fn expr(&self, tokens: &mut Peekable<IntoIter<Token>>) -> Result<i128, String> {
+        let mut value = self.term(tokens)?;
+        while let Some(Token::Symbol(op @ ('+' | '-'))) = tokens.peek().cloned() {
+            tokens.next();
+            let rhs = self.term(tokens)?;
+            value = if op == '+' { value + rhs } else { value - rhs };
+        }
+        Ok(value)
+    }
+
+    fn term(&self, tokens: &mut Peekable<IntoIter<Token>>) -> Result<i128, String> {
+        let mut value = self.factor(tokens)?;
+        while tokens.peek() == Some(&Token::Symbol('*')) {
+            tokens.next();
+            value *= self.factor(tokens)?;
+        }
+        Ok(value)
+    }
+
+    fn factor(&self, tokens: &mut Peekable<IntoIter<Token>>) -> Result<i128, String> {
+        match tokens.next() {
+            Some(Token::Number(number)) => Ok(number),
+            Some(Token::Symbol('(')) => {
+                let value = self.expr(tokens)?;
+                self.expect(tokens, ')')?;
+                Ok(value)
+            }
+            Some(Token::Ident(ident)) => match ident.as_str() {
+                "i" => Ok(self.i),
+                "maxLen" => Ok(self.max_len),
+                "IsZero" => {
+                    for symbol in ['(', ')', '('] {
+                        self.expect(tokens, symbol)?;
+                    }
+                    let value = self.expr(tokens)?;
+                    self.expect(tokens, ')')?;
+                    Ok((value == 0) as i128)
+                }
+                "IsEqual" => {
+                    for symbol in ['(', ')', '(', '['] {
+                        self.expect(tokens, symbol)?;
+                    }
+                    let lhs = self.expr(tokens)?;
+                    self.expect(tokens, ',')?;
+                    let rhs = self.expr(tokens)?;
+                    self.expect(tokens, ']')?;
+                    self.expect(tokens, ')')?;
+                    Ok((lhs == rhs) as i128)
+                }
+                _ => {
+                    let name = self.signal_name(ident, tokens)?;
+                    self.signals
+                        .get(&name)
+                        .copied()
+                        .ok_or_else(|| format!("{} is read before it is assigned", name))
+                }
+            },
+            other => Err(format!("unexpected token {:?}", other)),
+        }
+    }
+
+    fn signal_name(
+        &self,
+        ident: String,
+        tokens: &mut Peekable<IntoIter<Token>>,
+    ) -> Result<String, String> {
+        let mut name = ident;
+        while tokens.peek() == Some(&Token::Symbol('[')) {
+            tokens.next();
+            name.push_str(&format!("[{}]", self.expr(tokens)?));
+            self.expect(tokens, ']')?;
+        }
+        Ok(name)
+    }
+}
+
+fn execute(statement: &str, signals: &mut Signals, i: i128, max_len: i128) -> Result<(), String> {
+    let statement = statement.trim_end_matches(';');
+    let scope = Scope { signals, i, max_len };
+    if let Some((lhs, rhs)) = statement.split_once("<==") {
+        let mut tokens = tokenize(lhs);
+        let name = match tokens.next() {
+            Some(Token::Ident(ident)) => scope.signal_name(ident, &mut tokens)?,
+            other => return Err(format!("unexpected assignment target {:?}", other)),
+        };
+        let value = scope.expr(&mut tokenize(rhs))?;
+        match signals.insert(name.clone(), value) {
+            Some(witness) if witness != value => {
+                Err(format!("{} is {} in the witness but {} in the circuit", name, witness, value))
+            }
+            _ => Ok(()),
+        }
+    } else if let Some((lhs, rhs)) = statement.split_once("===") {
+        let (lhs, rhs) = (scope.expr(&mut tokenize(lhs))?, scope.expr(&mut tokenize(rhs))?);
+        if lhs == rhs {
+            Ok(())
+        } else {
+            Err(format!("{} fails with {} != {}", statement, lhs, rhs))
+        }
+    } else {
+        Err(format!("unsupported statement {}", statement))
+    }
+}
+
+/// Runs the template on `input`, starting from the `witness` signals already assigned.
+///
+/// Returns the final signals, or the first constraint the witness fails.
+fn evaluate(code: &str, input: &[u8], mut witness: Signals) -> Result<Signals, String> {
+    let max_len = MAX_LEN as i128;
+    for (i, &byte) in input.iter().chain([0u8].iter().cycle()).take(MAX_LEN).enumerate() {
+        witness.insert(format!("in[{}]", i), byte as i128);
+    }
+
+    let mut lines = code.lines().map(str::trim);
+    while let Some(line) = lines.next() {
+        if line.is_empty()
+            || line == "}"
+            || ["//", "pragma", "include", "template", "signal"].iter().any(|p| line.starts_with(p))
+        {
+            continue;
+        }
+        if let Some(start) = line.strip_prefix("for (var i = ") {
+            let start: i128 = start.split(';').next().unwrap().parse().unwrap();
+            let body: Vec<&str> = lines.by_ref().take_while(|line| *line != "}").collect();
+            for i in start..max_len {
+                for statement in body.iter().filter(|s| !s.is_empty() && !s.starts_with("//")) {
+                    execute(statement, &mut witness, i, max_len)?;
+                }
+            }
+            continue;
+        }
+        execute(line, &mut witness, 0, max_len)?;
+    }
+    Ok(witness)
+}
+
+/// Computes the state and reveal signals of `input` with the native simulation.
+fn native_witness(dfa: &DFAGraph, substrings: &[(usize, usize)], input: &[u8]) -> Signals {
+    let mut padded = input.to_vec();
+    padded.resize(MAX_LEN, 0);
+    let trace = dfa.run(&padded);
+    assert!(trace.accepted);
+
+    let mut witness = Signals::new();
+    for (i, state_id) in trace.states.iter().enumerate() {
+        for (index, state) in dfa.states.iter().enumerate() {
+            witness.insert(format!("states[{}][{}]", i, index), (state.state_id == *state_id) as i128);
+        }
+    }
+    if !substrings.is_empty() {
+        for (i, &byte) in padded.iter().enumerate() {
+            let step = (trace.states[i], trace.states[i + 1]);
+            let revealed = byte != 0 && substrings.contains(&step);
+            witness.insert(format!("reveal0[{}]", i), if revealed { byte as i128 } else { 0 });
+        }
+    }
+    witness
+}
+
+fn generate(regex: &str, substrings: &[(usize, usize)]) -> (DFAGraph, String) {
+    let regex_and_dfa = create_regex_and_dfa_from_str_and_defs(
+        regex,
+        SubstringDefinitionsJson { transitions: vec![substrings.to_vec()] },
+    )
+    .unwrap();
+    let substring_ranges: Vec<BTreeSet<(usize, usize)>> = regex_and_dfa
+        .substrings
+        .substring_ranges
+        .into_iter()
+        .filter(|ranges| !ranges.is_empty())
+        .collect();
+    let code = gen_circom_allstr(&regex_and_dfa.dfa, "RegexCircuit", regex, &substring_ranges);
+    (regex_and_dfa.dfa, code)
+}
+
+#[test]
+fn generated_templates_match_snapshots() {
+    let update = std::env::var_os("UPDATE_SNAPSHOTS").is_some();
+    for (path, regex, substrings, _) in SNAPSHOTS {
+        let (_, code) = generate(regex, substrings);
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(path);
+        if update {
+            fs::write(&path, code).unwrap();
+        } else {
+            let snapshot = fs::read_to_string(&path).unwrap();
+            assert!(
+                snapshot == code,
+                "{} is out of date; rerun with UPDATE_SNAPSHOTS=1",
+                path.display()
+            );
+        }
+    }
+}
+
+#[test]
+fn native_witness_satisfies_generated_templates() {
+    for (_, regex, substrings, inputs) in SNAPSHOTS {
+        let (dfa, code) = generate(regex, substrings);
+        for input in *inputs {
+            let input = input.as_bytes();
+            if dfa.run(input).accepted {
+                let witness = native_witness(&dfa, substrings, input);
+                if let Err(e) = evaluate(&code, input, witness) {
+                    panic!("{:?} of {} is rejected: {}", input.escape_ascii().to_string(), regex, e);
+                }
+            } else {
+                assert!(
+                    evaluate(&code, input, Signals::new()).is_err(),
+                    "{:?} of {} is accepted",
+                    input.escape_ascii().to_string(),
+                    regex
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn reveal_outputs_expose_substrings() {
+    let (_, regex, substrings, _) = SNAPSHOTS[0];
+    let (_, code) = generate(regex, substrings);
+    let signals = evaluate(&code, b"acbcbd", Signals::new()).unwrap();
+    let revealed: Vec<u8> = (0..MAX_LEN).map(|i| signals[&format!("reveal0[{}]", i)] as u8).collect();
+    assert_eq!(&revealed[..6], b"\0cbcb\0");
+    assert!(revealed[6..].iter().all(|&b| b == 0));
+}
+
+#[test]
+#[ignore = "needs circom, node and circomlib"]
+fn circom_compiles_and_runs_generated_templates() {
+    let circomlib = std::env::var("CIRCOMLIB_PATH").expect("CIRCOMLIB_PATH is not set");
+    let dir = std::env::temp_dir().join(format!("circom-check-{}", std::process::id()));
+    for (index, (_, regex, substrings, inputs)) in SNAPSHOTS.iter().enumerate() {
+        let (dfa, mut code) = generate(regex, substrings);
+        code.push_str(&format!("\ncomponent main {{public [in]}} = RegexCircuit({});\n", MAX_LEN));
+        let circuit_dir = dir.join(index.to_string());
+        fs::create_dir_all(&circuit_dir).unwrap();
+        fs::write(circuit_dir.join("main.circom"), code).unwrap();
+
+        let status = Command::new("circom")
+            .args(["main.circom", "--wasm", "-l", &circomlib])
+            .current_dir(&circuit_dir)
+            .status()
+            .unwrap();
+        assert!(status.success(), "circom rejected the template of {}", regex);
+
+        // Witness generation fails exactly when a constraint of the template does not hold
+        for input in *inputs {
+            let mut padded = input.as_bytes().to_vec();
+            padded.resize(MAX_LEN, 0);
+            let signals: Vec<String> = padded.iter().map(|b| format!("\"{}\"", b)).collect();
+            let input_json = format!("{{\"in\": [{}]}}", signals.join(", "));
+            fs::write(circuit_dir.join("input.json"), input_json).unwrap();
+            let status = Command::new("node")
+                .args([
+                    "main_js/generate_witness.js",
+                    "main_js/main.wasm",
+                    "input.json",
+                    "witness.wtns",
+                ])
+                .current_dir(&circuit_dir)
+                .status()
+                .unwrap();
+            assert_eq!(
+                status.success(),
+                dfa.run(input.as_bytes()).accepted,
+                "{:?} of {}",
+                input,
+                regex
+            );
+        }
+    }
+    fs::remove_dir_all(dir).unwrap();
+}
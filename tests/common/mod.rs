@@ -0,0 +1,18 @@
+//! Helpers shared by the integration tests.
+#![allow(dead_code)]
+
+use ark_bn254::Fr;
+use arkworks_regex_circuit::{
+    regex::create_regex_and_dfa_from_str_and_defs,
+    structs::{DFAGraph, SubstringDefinitionsJson},
+};
+
+pub fn dfa(regex: &str) -> DFAGraph {
+    create_regex_and_dfa_from_str_and_defs(regex, SubstringDefinitionsJson { transitions: vec![] })
+        .unwrap()
+        .dfa
+}
+
+pub fn salt() -> Fr {
+    Fr::from(0x5a17u64)
+}
@@ -0,0 +1,76 @@
+use ark_bls12_381::Bls12_381;
+use ark_bn254::Bn254;
+use ark_ec::pairing::Pairing;
+use ark_groth16::Groth16;
+use ark_std::rand::{rngs::StdRng, SeedableRng};
+use arkworks_regex_circuit::{
+    circuit::DFACircuit,
+    prover::{self, packed_public_inputs, public_inputs, Curve},
+    regex::create_regex_and_dfa_from_str_and_defs,
+    structs::SubstringDefinitionsJson,
+};
+
+const MAX_LEN: usize = 32;
+const REGEX: &str = "^[a-z]+[0-9]*@gmail\\.com$";
+
+/// Proves `input` against `REGEX` over `E` and checks the proof against matching and
+/// non-matching public inputs.
+fn prove_and_verify<E: Pairing>(input: &str, packed_input: bool) {
+    let mut rng = StdRng::seed_from_u64(0);
+    let dfa = create_regex_and_dfa_from_str_and_defs(
+        REGEX,
+        SubstringDefinitionsJson { transitions: vec![] },
+    )
+    .unwrap()
+    .dfa;
+    let mut circuit = DFACircuit::<E::ScalarField>::from_bytes(dfa, input.as_bytes(), MAX_LEN);
+    circuit.packed_input = packed_input;
+
+    let (pk, vk) = prover::setup::<_, Groth16<E>, _, _>(circuit.clone(), &mut rng).unwrap();
+    let proof = prover::prove::<_, Groth16<E>, _, _>(&pk, circuit, &mut rng).unwrap();
+
+    let public_for = |s: &str| {
+        if packed_input {
+            packed_public_inputs(s, MAX_LEN)
+        } else {
+            public_inputs(s, MAX_LEN)
+        }
+    };
+    assert!(prover::verify::<_, Groth16<E>>(&vk, &public_for(input), &proof).unwrap());
+    let other = public_for("bob7@gmail.com");
+    assert!(!prover::verify::<_, Groth16<E>>(&vk, &other, &proof).unwrap());
+}
+
+fn prove_and_verify_on(curve: Curve, packed_input: bool) {
+    match curve {
+        Curve::Bn254 => prove_and_verify::<Bn254>("alice42@gmail.com", packed_input),
+        Curve::Bls12_381 => prove_and_verify::<Bls12_381>("alice42@gmail.com", packed_input),
+    }
+}
+
+#[test]
+fn every_curve_proves_and_verifies() {
+    for curve in Curve::ALL {
+        prove_and_verify_on(curve, false);
+    }
+}
+
+#[test]
+fn every_curve_proves_and_verifies_packed_input() {
+    for curve in Curve::ALL {
+        prove_and_verify_on(curve, true);
+    }
+}
+
+#[test]
+fn curve_names_round_trip() {
+    for curve in Curve::ALL {
+        assert_eq!(curve.name().parse::<Curve>().unwrap(), curve);
+    }
+    assert_eq!("BLS12-381".parse::<Curve>().unwrap(), Curve::Bls12_381);
+    let err = "mnt4-298".parse::<Curve>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unsupported curve: \"mnt4-298\", expected one of: bn254, bls12-381"
+    );
+}
@@ -0,0 +1,76 @@
+use ark_bn254::Fr;
+use arkworks_regex_circuit::{
+    differential::{check_regex, compare, shrink},
+    errors::CompilerError,
+    regex::create_regex_and_dfa_from_str_and_defs,
+    structs::SubstringDefinitionsJson,
+};
+use regex::bytes::Regex;
+
+const MAX_LEN: usize = 16;
+
+/// Regexes paired with strings exercising matches, near misses and prefixes.
+const CORPUS: &[(&str, &[&str])] = &[
+    (r"a[bc]+d", &["abd", "acbd", "ad", "abc", "abdd", "xabd", ""]),
+    (
+        r"[a-zA-Z]+[0-9]*@gmail\.com",
+        &["bob@gmail.com", "b0b@gmail.com", "bob7@gmail.com", "@gmail.com", "bob@gmailxcom"],
+    ),
+    (r"(ab|cd)*e", &["e", "abe", "abcde", "ace", "abab", "cdcdcde"]),
+    (r"a|bc", &["a", "bc", "abc", "b", "ac", ""]),
+    (r"x?y{2,3}z", &["yyz", "xyyz", "xyyyz", "yz", "xyyyyz", "xxyyz"]),
+    (r"[^a]b", &["bb", "ab", "zb", "b", "bbb"]),
+    (r"\d{3}-\d{4}", &["555-1234", "55-1234", "555-12345", "555 1234"]),
+    (r"(foo|bar)baz?", &["fooba", "foobaz", "barbaz", "bazfoo", "foo"]),
+];
+
+#[test]
+fn oracles_agree_on_corpus() {
+    let mut failures = Vec::new();
+    for (regex, inputs) in CORPUS {
+        let inputs: Vec<&[u8]> = inputs.iter().map(|s| s.as_bytes()).collect();
+        failures.extend(check_regex::<Fr>(regex, &inputs, MAX_LEN).unwrap());
+    }
+    assert!(failures.is_empty(), "{:#?}", failures);
+}
+
+#[test]
+fn inputs_longer_than_max_len_are_skipped() {
+    let long = format!("a{}d", "b".repeat(MAX_LEN));
+    let regex_str = r"a[bc]+d";
+    assert!(check_regex::<Fr>(regex_str, &[long.as_bytes()], MAX_LEN).unwrap().is_empty());
+
+    let dfa = create_regex_and_dfa_from_str_and_defs(
+        regex_str,
+        SubstringDefinitionsJson { transitions: vec![] },
+    )
+    .unwrap()
+    .dfa;
+    let regex = Regex::new(&format!("^(?:{})$", regex_str)).unwrap();
+    let compared = compare::<Fr>(regex_str, &regex, &dfa, long.as_bytes(), MAX_LEN).unwrap();
+    assert!(compared.is_none());
+}
+
+#[test]
+fn unbalanced_patterns_are_rejected() {
+    // Wrapped as `^(?:a)(?:b)$`, this pattern would compile to the language {"ab"}
+    let regex_str = "a)(?:b";
+    assert!(matches!(
+        check_regex::<Fr>(regex_str, &[b"ab"], MAX_LEN),
+        Err(CompilerError::RegexError(_))
+    ));
+    assert!(matches!(
+        create_regex_and_dfa_from_str_and_defs(
+            regex_str,
+            SubstringDefinitionsJson { transitions: vec![] }
+        ),
+        Err(CompilerError::RegexError(_))
+    ));
+}
+
+#[test]
+fn shrink_finds_a_minimal_input() {
+    let minimal = shrink(b"hello world", |s| Ok(s.contains(&b'w') && s.len() > 1)).unwrap();
+    assert_eq!(minimal.len(), 2);
+    assert!(minimal.contains(&b'w'));
+}
@@ -0,0 +1,55 @@
+mod common;
+
+use common::dfa;
+
+#[test]
+fn equivalent_regexes_have_no_counterexample() {
+    assert!(dfa("a(b|c)").equivalent(&dfa("ab|ac")).is_ok());
+    assert!(dfa("a[bc]+d").equivalent(&dfa("a(b|c)+d")).is_ok());
+    assert!(dfa("(ab)*").equivalent(&dfa("(ab)*(ab)*")).is_ok());
+}
+
+#[test]
+fn counterexample_is_shortest() {
+    let counterexample = dfa("a+").equivalent(&dfa("a*")).unwrap_err();
+    assert_eq!(counterexample.input, b"");
+    assert!(!counterexample.accepted_by_left);
+    assert!(counterexample.accepted_by_right);
+
+    let counterexample = dfa("a[bc]d").equivalent(&dfa("a[b-e]d")).unwrap_err();
+    assert_eq!(counterexample.input, b"add");
+    assert!(!counterexample.accepted_by_left);
+}
+
+#[test]
+fn subset_checks_one_direction() {
+    let narrow = dfa("ab");
+    let wide = dfa("a[bc]");
+    assert!(narrow.is_subset_of(&wide).is_ok());
+
+    let counterexample = wide.is_subset_of(&narrow).unwrap_err();
+    assert_eq!(counterexample.input, b"ac");
+    assert!(counterexample.accepted_by_left);
+    assert!(!counterexample.accepted_by_right);
+}
+
+#[test]
+fn renumbered_graph_is_equivalent() {
+    let original = dfa("x(yz)+");
+    // Swap every state id except the start state with its mirror
+    let last = original.states.len() - 1;
+    let rename = |id: usize| if id == 0 { 0 } else { last + 1 - id };
+    let mut renumbered = original.clone();
+    for state in &mut renumbered.states {
+        state.state_id = rename(state.state_id);
+        state.transitions = state
+            .transitions
+            .iter()
+            .map(|(&to_state, chars)| (rename(to_state), chars.clone()))
+            .collect();
+    }
+    renumbered.states.sort_by_key(|s| s.state_id);
+
+    assert_ne!(original, renumbered);
+    assert!(original.equivalent(&renumbered).is_ok());
+}
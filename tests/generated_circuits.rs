@@ -0,0 +1,182 @@
+//! Snapshot and compile checks of the code emitted by `gen_arkworks_allstr`.
+//!
+//! Every snapshot under `tests/snapshots` is compiled into this test crate as a module, so a
+//! generator change that emits invalid Rust fails the build. Run with `UPDATE_SNAPSHOTS=1`
+//! to rewrite the snapshots after an intended change to the generator.
+
+mod common;
+
+use ark_bn254::Fr;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystem};
+use arkworks_regex_circuit::{
+    arkworks::gen_arkworks_allstr,
+    circuit::DFACircuit,
+    errors::CompilerError,
+    prover::encode_input,
+    regex::create_regex_and_dfa_from_str_and_defs,
+    structs::SubstringDefinitionsJson,
+};
+use common::dfa;
+use std::{fs, path::Path};
+
+#[path = "snapshots/a_bc_d.rs"]
+mod a_bc_d;
+#[path = "snapshots/alternation_star.rs"]
+mod alternation_star;
+#[path = "snapshots/gmail.rs"]
+mod gmail;
+#[path = "snapshots/gmail_packed.rs"]
+mod gmail_packed;
+#[path = "snapshots/negated_class.rs"]
+mod negated_class;
+
+const MAX_LEN: usize = 24;
+
+/// `(snapshot path, regex, packed_input, inputs)` of every checked circuit.
+const SNAPSHOTS: &[(&str, &str, bool, &[&str])] = &[
+    ("tests/snapshots/a_bc_d.rs", r"a[bc]+d", false, &["abcd", "ad", "abxd", ""]),
+    ("tests/snapshots/alternation_star.rs", r"(ab|cd)*e", false, &["e", "abcde", "abe", "ace"]),
+    (
+        "tests/snapshots/gmail.rs",
+        r"^[a-zA-Z]+[0-9]*@gmail\.com$",
+        false,
+        &["bob7@gmail.com", "bob@gmail.co", "7bob@gmail.com"],
+    ),
+    (
+        "tests/snapshots/gmail_packed.rs",
+        r"^[a-zA-Z]+[0-9]*@gmail\.com$",
+        true,
+        &["bob7@gmail.com", "bob@gmail.co", "7bob@gmail.com"],
+    ),
+    ("tests/snapshots/negated_class.rs", r"[^a]b", false, &["zb", "ab", "b"]),
+    (
+        "generated_circuit.rs",
+        r"^[a-zA-Z]+[0-9]*@gmail\.com$",
+        false,
+        &[],
+    ),
+    (
+        "example/src/generated_circuit.rs",
+        r"^[a-zA-Z]+[0-9]*@gmail\.com$",
+        false,
+        &[],
+    ),
+];
+
+#[test]
+fn generated_code_matches_snapshots() {
+    let update = std::env::var_os("UPDATE_SNAPSHOTS").is_some();
+    for (path, regex, packed_input, _) in SNAPSHOTS {
+        let regex_and_dfa = create_regex_and_dfa_from_str_and_defs(
+            regex,
+            SubstringDefinitionsJson { transitions: vec![] },
+        )
+        .unwrap();
+        let code = gen_arkworks_allstr(
+            &regex_and_dfa.dfa,
+            "RegexCircuit",
+            regex,
+            None,
+            *packed_input,
+        )
+        .unwrap();
+
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(path);
+        if update {
+            fs::write(&path, code).unwrap();
+        } else {
+            let snapshot = fs::read_to_string(&path).unwrap();
+            assert!(
+                snapshot == code,
+                "{} is out of date; rerun with UPDATE_SNAPSHOTS=1",
+                path.display()
+            );
+        }
+    }
+}
+
+/// Checks that a generated circuit and `DFACircuit` agree on constraints and satisfiability.
+macro_rules! check_generated {
+    ($module:ident, $index:expr) => {{
+        let (_, regex, packed_input, inputs) = SNAPSHOTS[$index];
+        let graph = dfa(regex);
+        for input in inputs {
+            let generated = ConstraintSystem::<Fr>::new_ref();
+            $module::RegexCircuit { input: encode_input::<Fr>(input), max_len: MAX_LEN }
+                .generate_constraints(generated.clone())
+                .unwrap();
+
+            let mut circuit = DFACircuit::<Fr>::from_bytes(graph.clone(), input.as_bytes(), MAX_LEN);
+            circuit.packed_input = packed_input;
+            let expected = ConstraintSystem::<Fr>::new_ref();
+            circuit.generate_constraints(expected.clone()).unwrap();
+
+            assert_eq!(generated.num_constraints(), expected.num_constraints(), "{:?}", input);
+            assert_eq!(
+                generated.num_instance_variables(),
+                expected.num_instance_variables(),
+                "{:?}",
+                input
+            );
+            assert_eq!(
+                generated.is_satisfied().unwrap(),
+                graph.run(input.as_bytes()).accepted,
+                "{:?}",
+                input
+            );
+            assert_eq!(generated.is_satisfied().unwrap(), expected.is_satisfied().unwrap());
+        }
+    }};
+}
+
+#[test]
+fn generated_circuits_agree_with_dfa_circuit() {
+    check_generated!(a_bc_d, 0);
+    check_generated!(alternation_star, 1);
+    check_generated!(gmail, 2);
+    check_generated!(gmail_packed, 3);
+    check_generated!(negated_class, 4);
+}
+
+#[test]
+fn invalid_struct_names_are_rejected() {
+    let graph = dfa(r"a[bc]+d");
+    for name in ["1Circuit", "Regex Circuit", "fn", ""] {
+        assert!(
+            matches!(
+                gen_arkworks_allstr(&graph, name, r"a[bc]+d", None, false),
+                Err(CompilerError::CodegenError(_))
+            ),
+            "{:?}",
+            name
+        );
+    }
+}
+
+#[test]
+fn transitions_without_bytes_are_never_taken() {
+    let mut graph = dfa(r"a[bc]+d");
+    graph.states[0].transitions.insert(2, Default::default());
+    let code = gen_arkworks_allstr(&graph, "RegexCircuit", r"a[bc]+d", None, false).unwrap();
+    assert!(code.contains("is_state_0.and(&(Boolean::constant(false)))?"));
+}
+
+#[test]
+fn regex_is_escaped_in_the_doc_comment() {
+    let regex = "\"*/\\\n";
+    let code = gen_arkworks_allstr(&dfa(r"a"), "RegexCircuit", regex, None, false).unwrap();
+    let file = syn::parse_file(&code).unwrap();
+    let doc = file
+        .items
+        .iter()
+        .find_map(|item| match item {
+            syn::Item::Struct(item) => item.attrs.first().cloned(),
+            _ => None,
+        })
+        .unwrap();
+    let syn::Meta::NameValue(doc) = doc.meta else { panic!("not a doc comment") };
+    let syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(doc), .. }) = doc.value else {
+        panic!("not a doc comment")
+    };
+    assert_eq!(doc.value(), " Regex: \"*/\\\\n");
+}
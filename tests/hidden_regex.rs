@@ -0,0 +1,109 @@
+mod common;
+
+use ark_bn254::{Bn254, Fr};
+use ark_ff::{UniformRand, Zero};
+use ark_groth16::Groth16;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystem};
+use ark_snark::SNARK;
+use ark_std::rand::{rngs::StdRng, SeedableRng};
+use arkworks_regex_circuit::{
+    structs::{DFAGraph, DFATable},
+    universal::{
+        canonicalize, hidden_regex_commitment, input_commitment, TableMode, UniversalRegexCircuit,
+    },
+};
+use common::dfa;
+use std::collections::BTreeMap;
+
+const MAX_STATES: usize = 8;
+const MAX_LEN: usize = 6;
+const SALT: u64 = 0x5a17;
+
+/// Renumbers every state but the start state in reverse order.
+fn shuffled(dfa: &DFAGraph) -> DFAGraph {
+    let n = dfa.states.len();
+    let rename = |id: usize| if id == 0 { 0 } else { n - id };
+    let mut shuffled = dfa.clone();
+    for state in shuffled.states.iter_mut() {
+        state.state_id = rename(state.state_id);
+        state.transitions = state
+            .transitions
+            .iter()
+            .map(|(&to_state, chars)| (rename(to_state), chars.clone()))
+            .collect::<BTreeMap<_, _>>();
+    }
+    shuffled.states.reverse();
+    shuffled
+}
+
+#[test]
+fn hidden_proof_only_exposes_the_commitments() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let empty = UniversalRegexCircuit::<Fr> {
+        table: DFATable::empty(MAX_STATES),
+        table_mode: TableMode::Committed,
+        table_salt: Fr::zero(),
+        input: vec![],
+        input_salt: Fr::zero(),
+        max_len: MAX_LEN,
+        packed_input: false,
+        private_input: true,
+    };
+    let (pk, vk) = Groth16::<Bn254>::circuit_specific_setup(empty, &mut rng).unwrap();
+    assert_eq!(vk.gamma_abc_g1.len(), 3);
+
+    let graph = dfa(r"^a[bc]+d$");
+    let (table_salt, input_salt) = (Fr::rand(&mut rng), Fr::rand(&mut rng));
+    let circuit = UniversalRegexCircuit::<Fr>::hidden(
+        &graph, b"abcd", MAX_STATES, MAX_LEN, table_salt, input_salt,
+    )
+    .unwrap();
+    let proof = Groth16::<Bn254>::prove(&pk, circuit, &mut rng).unwrap();
+
+    let input = input_commitment::<Fr>(b"abcd", MAX_LEN, input_salt);
+    let regex = hidden_regex_commitment::<Fr>(&graph, MAX_STATES, table_salt).unwrap();
+    assert!(Groth16::<Bn254>::verify(&vk, &[input, regex], &proof).unwrap());
+
+    // The proof is about this pattern and this string, not any other matching one
+    let other_regex = hidden_regex_commitment::<Fr>(&dfa(r"^a[bc]+e$"), MAX_STATES, table_salt);
+    assert!(!Groth16::<Bn254>::verify(&vk, &[input, other_regex.unwrap()], &proof).unwrap());
+    let other_input = input_commitment::<Fr>(b"abbd", MAX_LEN, input_salt);
+    assert!(!Groth16::<Bn254>::verify(&vk, &[other_input, regex], &proof).unwrap());
+}
+
+#[test]
+fn hidden_circuit_rejects_non_matching_input() {
+    let graph = dfa(r"^a[bc]+d$");
+    let cs = ConstraintSystem::<Fr>::new_ref();
+    let salt = Fr::from(SALT);
+    UniversalRegexCircuit::<Fr>::hidden(&graph, b"abd!", MAX_STATES, MAX_LEN, salt, salt)
+        .unwrap()
+        .generate_constraints(cs.clone())
+        .unwrap();
+
+    assert!(!cs.is_satisfied().unwrap());
+}
+
+#[test]
+fn commitments_are_blinded_by_their_salt() {
+    let graph = dfa(r"^a[bc]+d$");
+    let commitment = |salt: u64| hidden_regex_commitment::<Fr>(&graph, MAX_STATES, salt.into());
+    assert_eq!(commitment(SALT).unwrap(), commitment(SALT).unwrap());
+    assert_ne!(commitment(SALT).unwrap(), commitment(SALT + 1).unwrap());
+
+    let commitment = |salt: u64| input_commitment::<Fr>(b"abcd", MAX_LEN, salt.into());
+    assert_ne!(commitment(SALT), commitment(SALT + 1));
+}
+
+#[test]
+fn commitment_does_not_depend_on_state_numbering() {
+    let graph = dfa(r"^(ab|c)[0-9]*x$");
+    let shuffled = shuffled(&graph);
+    assert_ne!(graph, shuffled);
+
+    assert_eq!(canonicalize(&shuffled).unwrap(), canonicalize(&graph).unwrap());
+    assert_eq!(
+        hidden_regex_commitment::<Fr>(&shuffled, MAX_STATES, SALT.into()).unwrap(),
+        hidden_regex_commitment::<Fr>(&graph, MAX_STATES, SALT.into()).unwrap()
+    );
+}
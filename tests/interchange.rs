@@ -0,0 +1,87 @@
+use arkworks_regex_circuit::{
+    errors::CompilerError,
+    regex::create_regex_and_dfa_from_str_and_defs,
+    structs::{DFAGraph, RegexAndDFA, SubstringDefinitionsJson},
+};
+use std::collections::{BTreeMap, BTreeSet};
+
+fn regex_and_dfa(regex: &str) -> RegexAndDFA {
+    create_regex_and_dfa_from_str_and_defs(regex, SubstringDefinitionsJson { transitions: vec![] })
+        .unwrap()
+}
+
+fn assert_graph_error(dfa: &DFAGraph, expected: &str) {
+    match dfa.validate() {
+        Err(CompilerError::GraphError(message)) => {
+            assert!(message.contains(expected), "unexpected error: {}", message)
+        }
+        other => panic!("expected a graph error, got {:?}", other),
+    }
+}
+
+#[test]
+fn json_round_trips() {
+    let original = regex_and_dfa("a[bc]+d");
+    let json = original.to_json().unwrap();
+
+    let decoded = RegexAndDFA::from_json(&json).unwrap();
+    assert_eq!(decoded.regex_pattern, original.regex_pattern);
+    assert_eq!(decoded.dfa, original.dfa);
+
+    // A dumped `RegexAndDFA` and a bare graph both load as a graph
+    assert_eq!(DFAGraph::from_json(&json).unwrap(), original.dfa);
+    let graph_json = original.dfa.to_json().unwrap();
+    assert_eq!(DFAGraph::from_json(&graph_json).unwrap(), original.dfa);
+}
+
+#[test]
+fn compiled_graphs_validate() {
+    for regex in ["a[bc]+d", "(ab)*", "[a-zA-Z]+[0-9]+@gmail\\.com", "x|y?z"] {
+        regex_and_dfa(regex).dfa.validate().unwrap();
+    }
+}
+
+#[test]
+fn malformed_graphs_are_rejected() {
+    let dfa = regex_and_dfa("abc").dfa;
+
+    let mut duplicate = dfa.clone();
+    duplicate.states[1].state_id = duplicate.states[2].state_id;
+    assert_graph_error(&duplicate, "Duplicate state");
+
+    let mut no_start = dfa.clone();
+    no_start.states.retain(|s| s.state_id != 0);
+    assert_graph_error(&no_start, "Missing start state 0");
+
+    let mut dangling = dfa.clone();
+    dangling.states[0].transitions = BTreeMap::from([(99, BTreeSet::from([b'a']))]);
+    assert_graph_error(&dangling, "unknown state 99");
+
+    let mut nondeterministic = dfa.clone();
+    nondeterministic.states[0]
+        .transitions
+        .insert(2, BTreeSet::from([b'a']));
+    assert_graph_error(&nondeterministic, "Byte 97 leads from state 0");
+
+    let mut padding = dfa.clone();
+    padding.states[0].transitions.insert(1, BTreeSet::from([0, b'a']));
+    assert_graph_error(&padding, "padding byte 0");
+
+    let mut empty = dfa.clone();
+    empty.states[0].transitions.insert(2, BTreeSet::new());
+    assert_graph_error(&empty, "to state 2 takes no byte");
+    let empty_json = empty.to_json().unwrap();
+    assert!(matches!(DFAGraph::from_json(&empty_json), Err(CompilerError::GraphError(_))));
+
+    let mut unknown_type = dfa;
+    unknown_type.states[0].state_type = "reject".to_string();
+    assert_graph_error(&unknown_type, "Unknown type");
+}
+
+#[test]
+fn invalid_json_is_rejected() {
+    assert!(matches!(
+        DFAGraph::from_json("{\"states\": 3}"),
+        Err(CompilerError::JsonParseError(_))
+    ));
+}
@@ -0,0 +1,259 @@
+//! Snapshot and semantic checks of the Noir code emitted by `gen_noir_allstr`.
+//!
+//! nargo is not needed to run these tests: the transition table, accept states and reveal
+//! conditions are read back from the emitted code and run natively, following the loop of
+//! `regex_match`, then compared with the `regex` crate. Run with `UPDATE_SNAPSHOTS=1` to
+//! rewrite the snapshots.
+//!
+//! The ignored `nargo_compiles_and_runs_generated_programs` checks the programs with the
+//! real toolchain instead. It needs `nargo` on the `PATH`; run it with
+//! `cargo test --test noir -- --ignored`.
+
+use arkworks_regex_circuit::{
+    noir::gen_noir_allstr,
+    regex::create_regex_and_dfa_from_str_and_defs,
+    structs::SubstringDefinitionsJson,
+};
+use regex::Regex;
+use std::{collections::BTreeSet, fs, path::Path, process::Command};
+
+/// `(snapshot path, regex, substring transitions, inputs)` of a checked program.
+type Snapshot = (&'static str, &'static str, &'static [(usize, usize)], &'static [&'static str]);
+
+const SNAPSHOTS: &[Snapshot] = &[
+    (
+        "tests/snapshots/a_bc_d.nr",
+        r"a[bc]+d",
+        &[(1, 2), (2, 2)],
+        &["abcd", "acbcbd", "ad", "abxd"],
+    ),
+    (
+        "tests/snapshots/gmail.nr",
+        r"^[a-zA-Z]+[0-9]*@gmail\.com$",
+        &[],
+        &["bob7@gmail.com", "bob@gmail.co", "7bob@gmail.com", "B@gmail.com"],
+    ),
+    ("tests/snapshots/negated_class.nr", r"[^a]b", &[], &["zb", "ab", "b", "\x7fb"]),
+];
+
+/// A transition of `transition`: `(from, to, byte ranges)`.
+type Transition = (usize, usize, Vec<(u8, u8)>);
+
+/// The parts of a generated program needed to run it natively.
+struct Program {
+    dead_state: usize,
+    /// `(from, to, byte ranges)` of every `if` in `transition`, in order.
+    transitions: Vec<Transition>,
+    accept_states: BTreeSet<usize>,
+    /// The `(from, to)` pairs revealed in each row of `regex_reveal`.
+    reveals: Vec<Vec<(usize, usize)>>,
+}
+
+impl Program {
+    fn parse(code: &str) -> Self {
+        let number = Regex::new(r"\d+").unwrap();
+        let state_eq = Regex::new(r"state == (\d+)").unwrap();
+        let byte_eq = Regex::new(r"byte == (\d+)\)|byte >= (\d+)\) & \(byte <= (\d+)").unwrap();
+        let pair = Regex::new(r"state == (\d+)\) & \(next == (\d+)").unwrap();
+        let parse = |s: &str| s.parse::<usize>().unwrap();
+
+        let mut program = Program {
+            dead_state: 0,
+            transitions: vec![],
+            accept_states: BTreeSet::new(),
+            reveals: vec![],
+        };
+        let lines: Vec<&str> = code.lines().map(str::trim).collect();
+        let mut in_accept = false;
+        for (index, line) in lines.iter().enumerate() {
+            if line.starts_with("global NUM_STATES") {
+                program.dead_state = parse(number.find(line).unwrap().as_str());
+            } else if line.starts_with("fn is_accepting") {
+                in_accept = true;
+            } else if in_accept {
+                program.accept_states =
+                    state_eq.captures_iter(line).map(|c| parse(&c[1])).collect();
+                in_accept = false;
+            } else if line.starts_with("if (state == ") {
+                let from = parse(&state_eq.captures(line).unwrap()[1]);
+                let to = parse(number.find(lines[index + 1]).unwrap().as_str());
+                let ranges = byte_eq
+                    .captures_iter(line)
+                    .map(|c| match c.get(1) {
+                        Some(byte) => (parse(byte.as_str()) as u8, parse(byte.as_str()) as u8),
+                        None => (parse(&c[2]) as u8, parse(&c[3]) as u8),
+                    })
+                    .collect();
+                program.transitions.push((from, to, ranges));
+            } else if line.starts_with("if ((state == ") {
+                let pairs = pair.captures_iter(line).map(|c| (parse(&c[1]), parse(&c[2])));
+                program.reveals.push(pairs.collect());
+            }
+        }
+        program
+    }
+
+    fn transition(&self, state: usize, byte: u8) -> usize {
+        // A later `if` overrides an earlier one, as in the emitted code
+        self.transitions
+            .iter()
+            .rev()
+            .filter(|(from, _, ranges)| {
+                *from == state && ranges.iter().any(|&(start, end)| start <= byte && byte <= end)
+            })
+            .map(|(_, to, _)| *to)
+            .next()
+            .unwrap_or(self.dead_state)
+    }
+
+    /// Follows the loop of the emitted `regex_match` and `regex_reveal`.
+    fn run(&self, input: &[u8]) -> (bool, Vec<Vec<u8>>) {
+        let mut state = 0;
+        let mut valid = true;
+        let mut padding_started = false;
+        let mut reveal = vec![vec![0; input.len()]; self.reveals.len()];
+        for (i, &byte) in input.iter().enumerate() {
+            if byte == 0 {
+                padding_started = true;
+            } else {
+                valid &= !padding_started;
+                let next = self.transition(state, byte);
+                for (j, pairs) in self.reveals.iter().enumerate() {
+                    if pairs.contains(&(state, next)) {
+                        reveal[j][i] = byte;
+                    }
+                }
+                state = next;
+            }
+        }
+        (valid && self.accept_states.contains(&state), reveal)
+    }
+}
+
+fn generate(regex: &str, substrings: &[(usize, usize)]) -> String {
+    let regex_and_dfa = create_regex_and_dfa_from_str_and_defs(
+        regex,
+        SubstringDefinitionsJson { transitions: vec![substrings.to_vec()] },
+    )
+    .unwrap();
+    let substring_ranges: Vec<BTreeSet<(usize, usize)>> = regex_and_dfa
+        .substrings
+        .substring_ranges
+        .into_iter()
+        .filter(|ranges| !ranges.is_empty())
+        .collect();
+    gen_noir_allstr(&regex_and_dfa.dfa, "regex", regex, &substring_ranges)
+}
+
+#[test]
+fn generated_programs_match_snapshots() {
+    let update = std::env::var_os("UPDATE_SNAPSHOTS").is_some();
+    for (path, regex, substrings, _) in SNAPSHOTS {
+        let code = generate(regex, substrings);
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(path);
+        if update {
+            fs::write(&path, code).unwrap();
+        } else {
+            let snapshot = fs::read_to_string(&path).unwrap();
+            assert!(
+                snapshot == code,
+                "{} is out of date; rerun with UPDATE_SNAPSHOTS=1",
+                path.display()
+            );
+        }
+    }
+}
+
+#[test]
+fn transition_table_agrees_with_regex_crate() {
+    for (_, regex, substrings, inputs) in SNAPSHOTS {
+        let program = Program::parse(&generate(regex, substrings));
+        let expected = Regex::new(&format!("^(?:{})$", regex)).unwrap();
+
+        // Every string of up to 3 bytes over the literal bytes of the regex and a few others
+        let alphabet: BTreeSet<u8> = regex.bytes().chain(*b"xA0\x7f").collect();
+        let mut candidates: Vec<Vec<u8>> = vec![vec![]];
+        for _ in 0..3 {
+            let longer: Vec<Vec<u8>> = candidates
+                .iter()
+                .filter(|c| c.len() == candidates.last().unwrap().len())
+                .flat_map(|c| alphabet.iter().map(move |&b| [c.clone(), vec![b]].concat()))
+                .collect();
+            candidates.extend(longer);
+        }
+        candidates.extend(inputs.iter().map(|input| input.as_bytes().to_vec()));
+
+        for input in candidates {
+            let mut padded = input.clone();
+            padded.resize(input.len() + 2, 0);
+            let text = String::from_utf8(input.clone()).unwrap();
+            assert_eq!(
+                program.run(&padded).0,
+                expected.is_match(&text),
+                "{:?} of {}",
+                text,
+                regex
+            );
+        }
+    }
+}
+
+#[test]
+fn padding_inside_the_input_is_rejected() {
+    let program = Program::parse(&generate(SNAPSHOTS[0].1, &[]));
+    assert!(program.run(b"abcd\0\0").0);
+    assert!(!program.run(b"ab\0cd").0);
+}
+
+#[test]
+fn reveal_keeps_substring_bytes() {
+    let (_, regex, substrings, _) = SNAPSHOTS[0];
+    let program = Program::parse(&generate(regex, substrings));
+    let (matched, reveal) = program.run(b"acbcbd\0\0");
+    assert!(matched);
+    assert_eq!(reveal, vec![b"\0cbcb\0\0\0".to_vec()]);
+}
+
+
+#[test]
+#[ignore = "needs nargo"]
+fn nargo_compiles_and_runs_generated_programs() {
+    const INPUT_LEN: usize = 16;
+    let dir = std::env::temp_dir().join(format!("nargo-check-{}", std::process::id()));
+    for (index, (_, regex, substrings, inputs)) in SNAPSHOTS.iter().enumerate() {
+        let mut code = generate(regex, substrings);
+        code.push_str(&format!(
+            "\nfn main(input: pub [u8; {}]) {{\n    assert(regex_match(input));\n}}\n",
+            INPUT_LEN
+        ));
+        let project_dir = dir.join(format!("regex_{}", index));
+        fs::create_dir_all(project_dir.join("src")).unwrap();
+        fs::write(
+            project_dir.join("Nargo.toml"),
+            format!("[package]\nname = \"regex_{}\"\ntype = \"bin\"\nauthors = [\"\"]\n", index),
+        )
+        .unwrap();
+        fs::write(project_dir.join("src/main.nr"), code).unwrap();
+
+        // Execution fails exactly when the assertion of `main` does not hold
+        let expected = Regex::new(&format!("^(?:{})$", regex)).unwrap();
+        for input in *inputs {
+            let mut padded = input.as_bytes().to_vec();
+            padded.resize(INPUT_LEN, 0);
+            let bytes: Vec<String> = padded.iter().map(u8::to_string).collect();
+            let prover_toml = format!("input = [{}]\n", bytes.join(", "));
+            fs::write(project_dir.join("Prover.toml"), prover_toml).unwrap();
+            let output =
+                Command::new("nargo").arg("execute").current_dir(&project_dir).output().unwrap();
+            assert_eq!(
+                output.status.success(),
+                expected.is_match(input),
+                "{:?} of {}: {}",
+                input,
+                regex,
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+    }
+    fs::remove_dir_all(dir).unwrap();
+}
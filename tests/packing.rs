@@ -0,0 +1,72 @@
+mod common;
+
+use ark_bn254::{Bn254, Fr};
+use ark_groth16::Groth16;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystem, SynthesisError};
+use ark_snark::SNARK;
+use ark_std::rand::{rngs::StdRng, SeedableRng};
+use arkworks_regex_circuit::{
+    circuit::DFACircuit,
+    prover::{encode_input, packed_public_inputs},
+    structs::DFAGraph,
+};
+use common::dfa;
+
+const MAX_LEN: usize = 40;
+
+fn packed_circuit(dfa: DFAGraph, input: &str) -> DFACircuit<Fr> {
+    DFACircuit {
+        dfa,
+        input: encode_input(input),
+        max_len: MAX_LEN,
+        packed_input: true,
+    }
+}
+
+#[test]
+fn packed_public_inputs_are_little_endian_chunks() {
+    let inputs = packed_public_inputs::<Fr>("ab", MAX_LEN);
+
+    assert_eq!(inputs.len(), 2);
+    assert_eq!(inputs[0], Fr::from(b'a' as u64 + 256 * b'b' as u64));
+    assert_eq!(inputs[1], Fr::from(0u64));
+}
+
+#[test]
+fn packed_circuit_has_one_public_input_per_chunk() {
+    let cs = ConstraintSystem::<Fr>::new_ref();
+    packed_circuit(dfa("a[bc]+d"), "abcd")
+        .generate_constraints(cs.clone())
+        .unwrap();
+
+    assert!(cs.is_satisfied().unwrap());
+    assert_eq!(cs.num_instance_variables(), 1 + MAX_LEN.div_ceil(31));
+}
+
+#[test]
+fn packed_elements_above_255_are_rejected() {
+    let mut circuit = packed_circuit(dfa("a[bc]+d"), "abcd");
+    // The low byte of 'a' + 256 is still 'a'
+    circuit.input[0] += Fr::from(256u64);
+
+    assert!(circuit.check_input().is_err());
+    let cs = ConstraintSystem::<Fr>::new_ref();
+    assert!(matches!(circuit.generate_constraints(cs), Err(SynthesisError::Unsatisfiable)));
+}
+
+#[test]
+fn packed_proof_verifies_only_against_packed_input() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let dfa = dfa("^[a-z]+@[a-z]+\\.com$");
+    let input = "alice@example.com";
+
+    let (pk, vk) =
+        Groth16::<Bn254>::circuit_specific_setup(packed_circuit(dfa.clone(), ""), &mut rng).unwrap();
+    let proof = Groth16::<Bn254>::prove(&pk, packed_circuit(dfa, input), &mut rng).unwrap();
+
+    let public = packed_public_inputs::<Fr>(input, MAX_LEN);
+    assert!(Groth16::<Bn254>::verify(&vk, &public, &proof).unwrap());
+
+    let other = packed_public_inputs::<Fr>("alice@example.org", MAX_LEN);
+    assert!(!Groth16::<Bn254>::verify(&vk, &other, &proof).unwrap());
+}
@@ -0,0 +1,81 @@
+use ark_bn254::Fr;
+use arkworks_regex_circuit::{
+    differential::compare,
+    regex::create_regex_and_dfa_from_str_and_defs,
+    structs::SubstringDefinitionsJson,
+};
+use proptest::prelude::*;
+use regex::bytes::Regex;
+
+const MAX_LEN: usize = 8;
+
+/// Characters exercising the special cases of the DFA scraper: escapes, spaces and ranges.
+const ALPHABET: &[char] = &['a', 'b', 'c', ' ', '-', '.', '\n', '\\', ']', '\t'];
+
+/// A random regex AST over `ALPHABET`, rendered as a pattern string.
+fn regex_strategy() -> impl Strategy<Value = String> {
+    let leaf = prop_oneof![
+        prop::sample::select(ALPHABET).prop_map(|c| regex::escape(&c.to_string())),
+        prop::sample::subsequence(ALPHABET, 1..4).prop_map(|chars| {
+            let class: String = chars.iter().map(|c| regex::escape(&c.to_string())).collect();
+            format!("[{}]", class)
+        }),
+        prop::sample::subsequence(ALPHABET, 1..3).prop_map(|chars| {
+            let class: String = chars.iter().map(|c| regex::escape(&c.to_string())).collect();
+            format!("[^{}]", class)
+        }),
+        Just("[a-c]".to_string()),
+        Just(".".to_string()),
+    ];
+    leaf.prop_recursive(4, 16, 3, |inner| {
+        prop_oneof![
+            prop::collection::vec(inner.clone(), 2..4).prop_map(|parts| parts.concat()),
+            (inner.clone(), inner.clone()).prop_map(|(a, b)| format!("(?:{}|{})", a, b)),
+            inner.clone().prop_map(|r| format!("(?:{})*", r)),
+            inner.clone().prop_map(|r| format!("(?:{})+", r)),
+            inner.clone().prop_map(|r| format!("(?:{})?", r)),
+            (inner, 0..3u8, 0..2u8).prop_map(|(r, n, extra)| format!("(?:{}){{{},{}}}", r, n, n + extra)),
+        ]
+    })
+}
+
+/// Random strings over the same alphabet, plus a few bytes outside it.
+fn input_strategy() -> impl Strategy<Value = Vec<u8>> {
+    let byte = prop_oneof![
+        prop::sample::select(ALPHABET).prop_map(|c| c as u8),
+        Just(b'z'),
+        Just(0x7f),
+    ];
+    prop::collection::vec(byte, 0..=MAX_LEN)
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(128))]
+
+    #[test]
+    fn pipeline_agrees_with_regex_crate(
+        regex_str in regex_strategy(),
+        inputs in prop::collection::vec(input_strategy(), 1..6),
+    ) {
+        let dfa = create_regex_and_dfa_from_str_and_defs(
+            &regex_str,
+            SubstringDefinitionsJson { transitions: vec![] },
+        )
+        .unwrap()
+        .dfa;
+        let regex = Regex::new(&format!("^(?:{})$", regex_str)).unwrap();
+
+        for input in inputs {
+            let disagreement = compare::<Fr>(&regex_str, &regex, &dfa, &input, MAX_LEN).unwrap();
+            prop_assert!(disagreement.is_none(), "{:?}", disagreement);
+        }
+    }
+
+    #[test]
+    fn compiling_arbitrary_patterns_does_not_panic(pattern in "\\PC{0,12}") {
+        let _ = create_regex_and_dfa_from_str_and_defs(
+            &pattern,
+            SubstringDefinitionsJson { transitions: vec![] },
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use ark_bn254::Fr;
+use ark_ff::{BigInteger, PrimeField};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystem, ConstraintSystemRef};
+use arkworks_regex_circuit::{
+    circuit::DFACircuit,
+    r1cs::{full_assignment, write_r1cs, write_wtns},
+    regex::create_regex_and_dfa_from_str_and_defs,
+    structs::SubstringDefinitionsJson,
+};
+
+const MAX_LEN: usize = 16;
+
+fn synthesize(regex: &str, input: &str) -> ConstraintSystemRef<Fr> {
+    let regex_and_dfa = create_regex_and_dfa_from_str_and_defs(
+        regex,
+        SubstringDefinitionsJson { transitions: vec![] },
+    )
+    .unwrap();
+    let circuit = DFACircuit::<Fr>::from_bytes(regex_and_dfa.dfa, input.as_bytes(), MAX_LEN);
+
+    let cs = ConstraintSystem::<Fr>::new_ref();
+    circuit.generate_constraints(cs.clone()).unwrap();
+    cs.finalize();
+    cs
+}
+
+/// Minimal reader for the files produced by `write_r1cs` / `write_wtns`.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> &'a [u8] {
+        let slice = &self.bytes[self.pos..self.pos + n];
+        self.pos += n;
+        slice
+    }
+
+    fn u32(&mut self) -> u32 {
+        u32::from_le_bytes(self.take(4).try_into().unwrap())
+    }
+
+    fn u64(&mut self) -> u64 {
+        u64::from_le_bytes(self.take(8).try_into().unwrap())
+    }
+
+    fn field(&mut self) -> Fr {
+        Fr::from_le_bytes_mod_order(self.take(32))
+    }
+
+    fn linear_combination(&mut self) -> Vec<(usize, Fr)> {
+        let nnz = self.u32();
+        (0..nnz).map(|_| (self.u32() as usize, self.field())).collect()
+    }
+}
+
+#[test]
+fn r1cs_and_wtns_agree_with_constraint_system() {
+    let cs = synthesize("a[bc]+d", "abcbd");
+    assert!(cs.is_satisfied().unwrap());
+
+    let matrices = cs.to_matrices().unwrap();
+    let mut r1cs = Vec::new();
+    write_r1cs(&matrices, &mut r1cs).unwrap();
+    let witness = full_assignment(&cs).unwrap();
+    let mut wtns = Vec::new();
+    write_wtns(&witness, &mut wtns).unwrap();
+
+    // .wtns: magic, version, sections, header section, values section
+    let mut reader = Reader { bytes: &wtns, pos: 0 };
+    assert_eq!(reader.take(4), b"wtns");
+    assert_eq!(reader.u32(), 2);
+    assert_eq!(reader.u32(), 2);
+    assert_eq!((reader.u32(), reader.u64()), (1, 40));
+    assert_eq!(reader.u32(), 32);
+    assert_eq!(reader.take(32), Fr::MODULUS.to_bytes_le().as_slice());
+    let n_witness = reader.u32() as usize;
+    assert_eq!(n_witness, witness.len());
+    assert_eq!((reader.u32(), reader.u64()), (2, 32 * n_witness as u64));
+    let values: Vec<Fr> = (0..n_witness).map(|_| reader.field()).collect();
+    assert_eq!(values[0], Fr::from(1u64));
+    assert_eq!(values[1], Fr::from(b'a' as u64));
+
+    // .r1cs: magic, version, sections, header section, constraints section, labels section
+    let mut reader = Reader { bytes: &r1cs, pos: 0 };
+    assert_eq!(reader.take(4), b"r1cs");
+    assert_eq!(reader.u32(), 1);
+    assert_eq!(reader.u32(), 3);
+    assert_eq!(reader.u32(), 1);
+    reader.u64();
+    assert_eq!(reader.u32(), 32);
+    assert_eq!(reader.take(32), Fr::MODULUS.to_bytes_le().as_slice());
+    assert_eq!(reader.u32() as usize, n_witness);
+    assert_eq!(reader.u32(), 0);
+    assert_eq!(reader.u32() as usize, MAX_LEN);
+    assert_eq!(reader.u32(), 0);
+    assert_eq!(reader.u64() as usize, n_witness);
+    let n_constraints = reader.u32() as usize;
+    assert_eq!(n_constraints, cs.num_constraints());
+
+    assert_eq!(reader.u32(), 2);
+    reader.u64();
+    let evaluate = |lc: &[(usize, Fr)]| -> Fr { lc.iter().map(|(w, c)| values[*w] * c).sum() };
+    for _ in 0..n_constraints {
+        let a = reader.linear_combination();
+        let b = reader.linear_combination();
+        let c = reader.linear_combination();
+        assert_eq!(evaluate(&a) * evaluate(&b), evaluate(&c));
+    }
+
+    assert_eq!((reader.u32(), reader.u64()), (3, 8 * n_witness as u64));
+    assert_eq!(reader.u64(), 0);
+    reader.take(8 * (n_witness - 1));
+    assert_eq!(reader.pos, r1cs.len());
+}
+
+#[test]
+fn r1cs_is_shared_between_inputs() {
+    let matching = synthesize("a[bc]+d", "abd").to_matrices().unwrap();
+    let rejected = synthesize("a[bc]+d", "abx");
+
+    assert!(!rejected.is_satisfied().unwrap());
+
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+    write_r1cs(&matching, &mut left).unwrap();
+    write_r1cs(&rejected.to_matrices().unwrap(), &mut right).unwrap();
+    assert_eq!(left, right);
+}
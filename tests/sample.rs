@@ -0,0 +1,89 @@
+mod common;
+
+use arkworks_regex_circuit::{
+    structs::{DFAGraph, DFAStateNode},
+};
+use common::dfa;
+use rand::{rngs::StdRng, SeedableRng};
+use regex::bytes::Regex;
+
+const REGEXES: &[&str] = &[
+    r"a[bc]+d",
+    r"(ab|cd)*e",
+    r"^[a-zA-Z]+[0-9]*@gmail\.com$",
+    r"[^a]b",
+    r"(xy)*",
+];
+
+#[test]
+fn samples_are_accepted_and_rejected_as_claimed() {
+    let mut rng = StdRng::seed_from_u64(7);
+    for regex in REGEXES {
+        let graph = dfa(regex);
+        for _ in 0..50 {
+            let input = graph.sample_accepted(&mut rng, 16).unwrap();
+            assert!(input.len() <= 16 && !input.contains(&0), "{:?}", input);
+            assert!(graph.run(&input).accepted, "{} rejects {:?}", regex, input);
+
+            let input = graph.sample_rejected(&mut rng, 16).unwrap();
+            assert!(input.len() <= 16 && !input.contains(&0), "{:?}", input);
+            assert!(!graph.run(&input).accepted, "{} accepts {:?}", regex, input);
+        }
+    }
+}
+
+#[test]
+fn samples_depend_only_on_the_seed() {
+    let graph = dfa(r"^[a-zA-Z]+[0-9]*@gmail\.com$");
+    let draw = |seed| {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..10)
+            .map(|_| (graph.sample_accepted(&mut rng, 32), graph.sample_rejected(&mut rng, 32)))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(draw(3), draw(3));
+    assert_ne!(draw(3), draw(4));
+}
+
+#[test]
+fn sampling_fails_when_no_string_fits() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let graph = dfa(r"a[bc]+d");
+    assert_eq!(graph.sample_accepted(&mut rng, 2), None);
+    assert!(graph.sample_rejected(&mut rng, 2).is_some());
+
+    // A single accept state looping on every byte accepts every string
+    let graph = DFAGraph {
+        states: vec![DFAStateNode {
+            state_type: "accept".to_string(),
+            state_id: 0,
+            transitions: [(0, (1..=u8::MAX).collect())].into(),
+        }],
+    };
+    assert!(graph.sample_accepted(&mut rng, 4).is_some());
+    assert_eq!(graph.sample_rejected(&mut rng, 4), None);
+}
+
+#[test]
+fn shortest_accepted_agrees_with_brute_force() {
+    for (regex, alphabet) in [(r"a[bc]+d", "abcd"), (r"(ab|cd)*e", "abcde"), (r"(xy)*", "xy")] {
+        let expected = Regex::new(&format!("^(?:{})$", regex)).unwrap();
+        let mut candidates: Vec<Vec<u8>> = vec![vec![]];
+        for len in 1..=5 {
+            let longer: Vec<Vec<u8>> = candidates
+                .iter()
+                .filter(|c| c.len() == len - 1)
+                .flat_map(|c| alphabet.bytes().map(move |b| [c.as_slice(), &[b]].concat()))
+                .collect();
+            candidates.extend(longer);
+        }
+        let brute_force: Vec<Vec<u8>> =
+            candidates.into_iter().filter(|c| expected.is_match(c)).take(6).collect();
+
+        assert_eq!(dfa(regex).shortest_accepted(6, 5), brute_force, "{}", regex);
+    }
+
+    // Fewer strings than asked for
+    assert_eq!(dfa(r"ab|").shortest_accepted(5, 8), vec![b"".to_vec(), b"ab".to_vec()]);
+    assert_eq!(dfa(r"a[bc]+d").shortest_accepted(5, 3), vec![b"abd".to_vec(), b"acd".to_vec()]);
+}
@@ -0,0 +1,144 @@
+//! Checks of the prover project written by `gen_crate`.
+//!
+//! Building the project needs its own dependency tree, so most of these tests check what it
+//! is built from: the files parse, the embedded regex and circuit are the expected ones, and
+//! the example strings of its tests are accepted and rejected as claimed. The ignored
+//! `generated_crate_passes_its_tests` builds and tests the project itself; run it with
+//! `cargo test --test scaffold -- --ignored`.
+
+mod common;
+
+use arkworks_regex_circuit::{
+    arkworks::gen_arkworks_allstr,
+    errors::CompilerError,
+    prover::Curve,
+    scaffold::{examples, gen_crate, write_crate, CrateOptions},
+};
+use common::dfa;
+use std::{fs, path::PathBuf, process::Command};
+
+/// Returns the string value of the `const` called `name` in `code`.
+fn const_str(code: &str, name: &str) -> String {
+    syn::parse_file(code)
+        .unwrap()
+        .items
+        .into_iter()
+        .find_map(|item| match item {
+            syn::Item::Const(item) if item.ident == name => match *item.expr {
+                syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(lit), .. }) => Some(lit.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .unwrap()
+}
+
+#[test]
+fn crate_embeds_the_regex_and_its_circuit() {
+    let regex = r#"^[a-z]+"\{max_len\}@mail\.com$"#;
+    let graph = dfa(regex);
+    let options = CrateOptions {
+        name: "mail-prover".to_string(),
+        curve: Curve::Bls12_381,
+        packed_input: true,
+        ..Default::default()
+    };
+    let files = gen_crate(&graph, regex, &options).unwrap();
+
+    let paths: Vec<&str> = files.keys().map(|path| path.to_str().unwrap()).collect();
+    assert_eq!(
+        paths,
+        ["Cargo.toml", "src/generated_circuit.rs", "src/lib.rs", "src/main.rs", "tests/examples.rs"]
+    );
+    let manifest = &files[&PathBuf::from("Cargo.toml")];
+    assert!(manifest.contains("name = \"mail-prover\""));
+    assert!(manifest.contains("ark-bls12-381 = \"0.4\""));
+
+    let lib = &files[&PathBuf::from("src/lib.rs")];
+    assert_eq!(const_str(lib, "REGEX"), regex);
+    assert!(lib.contains("pub const MAX_LEN: usize = RegexCircuit::<Fr>::MAX_LEN;"));
+    assert!(lib.contains("use ark_bls12_381::{Bls12_381 as Curve, Fr};"));
+    assert!(lib.contains("bytes.chunks(31)"));
+    assert!(files[&PathBuf::from("src/main.rs")].contains("use mail_prover::"));
+    let circuit = &files[&PathBuf::from("src/generated_circuit.rs")];
+    let expected = gen_arkworks_allstr(&graph, "RegexCircuit", regex, Some(256), true).unwrap();
+    assert_eq!(*circuit, expected);
+    assert!(circuit.contains("pub const MAX_LEN: usize = 256;"));
+}
+
+#[test]
+fn examples_are_accepted_and_rejected_as_claimed() {
+    for regex in [r"a[bc]+d", r"(ab|cd)*e", r"^[a-zA-Z]+[0-9]*@gmail\.com$", r"[^a]b", r"(xy)*"] {
+        let graph = dfa(regex);
+        let (accepted, rejected) = examples(&graph, 5, 16);
+        assert_eq!(accepted.len(), 5, "{}", regex);
+        assert!(!rejected.is_empty(), "{}", regex);
+        assert!(accepted.windows(2).all(|pair| pair[0].len() <= pair[1].len()), "{}", regex);
+        for input in accepted.iter().chain(&rejected) {
+            assert!(input.len() <= 16 && !input.contains(&0), "{:?}", input);
+        }
+        assert!(accepted.iter().all(|input| graph.run(input).accepted), "{}", regex);
+        assert!(rejected.iter().all(|input| !graph.run(input).accepted), "{}", regex);
+    }
+
+    // Only two strings are accepted, and only the empty one fits in one byte
+    let (accepted, _) = examples(&dfa(r"ab|"), 5, 1);
+    assert_eq!(accepted, vec![b"".to_vec()]);
+}
+
+#[test]
+fn invalid_package_names_are_rejected() {
+    let graph = dfa(r"a[bc]+d");
+    for name in ["", "1prover", "regex prover", "fn", "prover!"] {
+        let options = CrateOptions { name: name.to_string(), ..Default::default() };
+        assert!(
+            matches!(gen_crate(&graph, r"a[bc]+d", &options), Err(CompilerError::CodegenError(_))),
+            "{:?}",
+            name
+        );
+    }
+}
+
+#[test]
+fn crate_is_written_to_its_directory() {
+    let files = gen_crate(&dfa(r"a[bc]+d"), r"a[bc]+d", &CrateOptions::default()).unwrap();
+    let dir = std::env::temp_dir().join(format!("scaffold-{}", std::process::id()));
+    write_crate(&dir, &files).unwrap();
+    for (path, contents) in &files {
+        assert_eq!(&fs::read_to_string(dir.join(path)).unwrap(), contents);
+    }
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+#[ignore = "builds the generated crate and its dependency tree"]
+fn generated_crate_passes_its_tests() {
+    let regex = r"^[a-zA-Z]+[0-9]*@gmail\.com$";
+    let graph = dfa(regex);
+    let dir = std::env::temp_dir().join(format!("scaffold-build-{}", std::process::id()));
+    for packed_input in [false, true] {
+        let options = CrateOptions {
+            name: "gmail-prover".to_string(),
+            max_len: 32,
+            packed_input,
+            ..Default::default()
+        };
+        let crate_dir = dir.join(format!("packed-{}", packed_input));
+        write_crate(&crate_dir, &gen_crate(&graph, regex, &options).unwrap()).unwrap();
+
+        // Both projects share a target directory, so the dependencies are built once
+        let status = Command::new(env!("CARGO"))
+            .args(["test", "--offline"])
+            .current_dir(&crate_dir)
+            .env("CARGO_TARGET_DIR", dir.join("target"))
+            .status()
+            .unwrap();
+        assert!(
+            status.success(),
+            "cargo test failed in {} (packed: {})",
+            crate_dir.display(),
+            packed_input
+        );
+    }
+    fs::remove_dir_all(dir).unwrap();
+}
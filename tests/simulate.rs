@@ -0,0 +1,69 @@
+mod common;
+
+use ark_bn254::{Bn254, Fr};
+use ark_groth16::Groth16;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystem};
+use ark_std::rand::{rngs::StdRng, SeedableRng};
+use arkworks_regex_circuit::{circuit::DFACircuit, errors::CompilerError, prover};
+use common::dfa;
+
+const MAX_LEN: usize = 8;
+
+#[test]
+fn trace_records_states_and_acceptance() {
+    let graph = dfa(r"^a[bc]+d$");
+
+    let trace = graph.run(b"abcd");
+    assert_eq!(trace.states.len(), 5);
+    assert_eq!(trace.states[0], 0);
+    assert_eq!(trace.stuck_at, None);
+    assert!(trace.accepted);
+
+    let prefix = graph.run(b"abc");
+    assert_eq!(prefix.states, trace.states[..4]);
+    assert!(!prefix.accepted);
+
+    let stuck = graph.run(b"abxd");
+    assert_eq!(stuck.stuck_at, Some(2));
+    assert_eq!(stuck.states, trace.states[..3]);
+    assert!(!stuck.accepted);
+}
+
+#[test]
+fn check_names_the_failing_offset() {
+    let graph = dfa(r"^a[bc]+d$");
+
+    let err = graph.check(b"abxd").unwrap_err();
+    assert!(matches!(err, CompilerError::InputRejected(_)));
+    assert!(err.to_string().contains("offset 2"), "{}", err);
+
+    let err = graph.check(b"ab").unwrap_err();
+    assert!(err.to_string().contains("non-accepting"), "{}", err);
+}
+
+#[test]
+fn simulation_agrees_with_the_circuit() {
+    let graph = dfa(r"^[a-z]+[0-9]*!$");
+
+    for input in ["abc!", "a1!", "x99!", "", "!", "a!b", "AB!", "abc", "a\0b!", "abcdefgh!"] {
+        let circuit = DFACircuit::<Fr>::from_bytes(graph.clone(), input.as_bytes(), MAX_LEN);
+        let check = circuit.check_input();
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        // Inputs longer than max_len cannot be synthesized at all
+        let satisfied =
+            circuit.generate_constraints(cs.clone()).is_ok() && cs.is_satisfied().unwrap();
+        assert_eq!(check.is_ok(), satisfied, "input {:?}", input);
+    }
+}
+
+#[test]
+fn prove_checked_rejects_non_matching_input() {
+    let graph = dfa(r"^a[bc]+d$");
+    let mut rng = StdRng::seed_from_u64(0);
+    let setup_circuit = DFACircuit::<Fr>::from_bytes(graph.clone(), b"", MAX_LEN);
+    let (pk, _) = prover::setup::<_, Groth16<Bn254>, _, _>(setup_circuit, &mut rng).unwrap();
+
+    let circuit = DFACircuit::<Fr>::from_bytes(graph, b"abxd", MAX_LEN);
+    let result = prover::prove_checked::<_, Groth16<Bn254>, _>(&pk, circuit, &mut rng);
+    assert!(matches!(result, Err(CompilerError::InputRejected(_))));
+}
@@ -0,0 +1,54 @@
+pragma circom 2.1.5;
+
+include "circomlib/circuits/comparators.circom";
+
+// Regex: (ab|cd)*e
+template RegexCircuit(maxLen) {
+    signal input in[maxLen];
+    signal output reveal0[maxLen];
+
+    // One-hot encoding of the state before every byte (4 states)
+    signal states[maxLen + 1][4];
+    signal isPad[maxLen];
+    signal isByte[maxLen][5];
+    signal trans[maxLen][5];
+    signal stay[maxLen][4];
+
+    states[0][0] <== 1;
+    states[0][1] <== 0;
+    states[0][2] <== 0;
+    states[0][3] <== 0;
+
+    for (var i = 0; i < maxLen; i++) {
+        isPad[i] <== IsZero()(in[i]);
+        isByte[i][0] <== IsEqual()([in[i], 97]);
+        isByte[i][1] <== IsEqual()([in[i], 98]);
+        isByte[i][2] <== IsEqual()([in[i], 99]);
+        isByte[i][3] <== IsEqual()([in[i], 100]);
+        isByte[i][4] <== IsEqual()([in[i], 101]);
+        trans[i][0] <== states[i][0] * isByte[i][0];
+        trans[i][1] <== states[i][0] * isByte[i][2];
+        trans[i][2] <== states[i][0] * isByte[i][4];
+        trans[i][3] <== states[i][1] * isByte[i][1];
+        trans[i][4] <== states[i][2] * isByte[i][3];
+        stay[i][0] <== isPad[i] * states[i][0];
+        states[i + 1][0] <== trans[i][3] + trans[i][4] + stay[i][0];
+        stay[i][1] <== isPad[i] * states[i][1];
+        states[i + 1][1] <== trans[i][0] + stay[i][1];
+        stay[i][2] <== isPad[i] * states[i][2];
+        states[i + 1][2] <== trans[i][1] + stay[i][2];
+        stay[i][3] <== isPad[i] * states[i][3];
+        states[i + 1][3] <== trans[i][2] + stay[i][3];
+        // A byte without a transition leaves no state active
+        states[i + 1][0] + states[i + 1][1] + states[i + 1][2] + states[i + 1][3] === 1;
+        reveal0[i] <== in[i] * (trans[i][0]);
+    }
+
+    // Padding may only be followed by more padding
+    for (var i = 1; i < maxLen; i++) {
+        isPad[i - 1] * (1 - isPad[i]) === 0;
+    }
+
+    // The run must end in an accept state
+    states[maxLen][3] === 1;
+}
@@ -0,0 +1,148 @@
+pragma circom 2.1.5;
+
+include "circomlib/circuits/comparators.circom";
+
+// Regex: ^[a-zA-Z]+[0-9]*@gmail\.com$
+template RegexCircuit(maxLen) {
+    signal input in[maxLen];
+
+    // One-hot encoding of the state before every byte (13 states)
+    signal states[maxLen + 1][13];
+    signal isPad[maxLen];
+    signal isByte[maxLen][64];
+    signal trans[maxLen][15];
+    signal stay[maxLen][13];
+
+    states[0][0] <== 1;
+    states[0][1] <== 0;
+    states[0][2] <== 0;
+    states[0][3] <== 0;
+    states[0][4] <== 0;
+    states[0][5] <== 0;
+    states[0][6] <== 0;
+    states[0][7] <== 0;
+    states[0][8] <== 0;
+    states[0][9] <== 0;
+    states[0][10] <== 0;
+    states[0][11] <== 0;
+    states[0][12] <== 0;
+
+    for (var i = 0; i < maxLen; i++) {
+        isPad[i] <== IsZero()(in[i]);
+        isByte[i][0] <== IsEqual()([in[i], 46]);
+        isByte[i][1] <== IsEqual()([in[i], 48]);
+        isByte[i][2] <== IsEqual()([in[i], 49]);
+        isByte[i][3] <== IsEqual()([in[i], 50]);
+        isByte[i][4] <== IsEqual()([in[i], 51]);
+        isByte[i][5] <== IsEqual()([in[i], 52]);
+        isByte[i][6] <== IsEqual()([in[i], 53]);
+        isByte[i][7] <== IsEqual()([in[i], 54]);
+        isByte[i][8] <== IsEqual()([in[i], 55]);
+        isByte[i][9] <== IsEqual()([in[i], 56]);
+        isByte[i][10] <== IsEqual()([in[i], 57]);
+        isByte[i][11] <== IsEqual()([in[i], 64]);
+        isByte[i][12] <== IsEqual()([in[i], 65]);
+        isByte[i][13] <== IsEqual()([in[i], 66]);
+        isByte[i][14] <== IsEqual()([in[i], 67]);
+        isByte[i][15] <== IsEqual()([in[i], 68]);
+        isByte[i][16] <== IsEqual()([in[i], 69]);
+        isByte[i][17] <== IsEqual()([in[i], 70]);
+        isByte[i][18] <== IsEqual()([in[i], 71]);
+        isByte[i][19] <== IsEqual()([in[i], 72]);
+        isByte[i][20] <== IsEqual()([in[i], 73]);
+        isByte[i][21] <== IsEqual()([in[i], 74]);
+        isByte[i][22] <== IsEqual()([in[i], 75]);
+        isByte[i][23] <== IsEqual()([in[i], 76]);
+        isByte[i][24] <== IsEqual()([in[i], 77]);
+        isByte[i][25] <== IsEqual()([in[i], 78]);
+        isByte[i][26] <== IsEqual()([in[i], 79]);
+        isByte[i][27] <== IsEqual()([in[i], 80]);
+        isByte[i][28] <== IsEqual()([in[i], 81]);
+        isByte[i][29] <== IsEqual()([in[i], 82]);
+        isByte[i][30] <== IsEqual()([in[i], 83]);
+        isByte[i][31] <== IsEqual()([in[i], 84]);
+        isByte[i][32] <== IsEqual()([in[i], 85]);
+        isByte[i][33] <== IsEqual()([in[i], 86]);
+        isByte[i][34] <== IsEqual()([in[i], 87]);
+        isByte[i][35] <== IsEqual()([in[i], 88]);
+        isByte[i][36] <== IsEqual()([in[i], 89]);
+        isByte[i][37] <== IsEqual()([in[i], 90]);
+        isByte[i][38] <== IsEqual()([in[i], 97]);
+        isByte[i][39] <== IsEqual()([in[i], 98]);
+        isByte[i][40] <== IsEqual()([in[i], 99]);
+        isByte[i][41] <== IsEqual()([in[i], 100]);
+        isByte[i][42] <== IsEqual()([in[i], 101]);
+        isByte[i][43] <== IsEqual()([in[i], 102]);
+        isByte[i][44] <== IsEqual()([in[i], 103]);
+        isByte[i][45] <== IsEqual()([in[i], 104]);
+        isByte[i][46] <== IsEqual()([in[i], 105]);
+        isByte[i][47] <== IsEqual()([in[i], 106]);
+        isByte[i][48] <== IsEqual()([in[i], 107]);
+        isByte[i][49] <== IsEqual()([in[i], 108]);
+        isByte[i][50] <== IsEqual()([in[i], 109]);
+        isByte[i][51] <== IsEqual()([in[i], 110]);
+        isByte[i][52] <== IsEqual()([in[i], 111]);
+        isByte[i][53] <== IsEqual()([in[i], 112]);
+        isByte[i][54] <== IsEqual()([in[i], 113]);
+        isByte[i][55] <== IsEqual()([in[i], 114]);
+        isByte[i][56] <== IsEqual()([in[i], 115]);
+        isByte[i][57] <== IsEqual()([in[i], 116]);
+        isByte[i][58] <== IsEqual()([in[i], 117]);
+        isByte[i][59] <== IsEqual()([in[i], 118]);
+        isByte[i][60] <== IsEqual()([in[i], 119]);
+        isByte[i][61] <== IsEqual()([in[i], 120]);
+        isByte[i][62] <== IsEqual()([in[i], 121]);
+        isByte[i][63] <== IsEqual()([in[i], 122]);
+        trans[i][0] <== states[i][0] * (isByte[i][12] + isByte[i][13] + isByte[i][14] + isByte[i][15] + isByte[i][16] + isByte[i][17] + isByte[i][18] + isByte[i][19] + isByte[i][20] + isByte[i][21] + isByte[i][22] + isByte[i][23] + isByte[i][24] + isByte[i][25] + isByte[i][26] + isByte[i][27] + isByte[i][28] + isByte[i][29] + isByte[i][30] + isByte[i][31] + isByte[i][32] + isByte[i][33] + isByte[i][34] + isByte[i][35] + isByte[i][36] + isByte[i][37] + isByte[i][38] + isByte[i][39] + isByte[i][40] + isByte[i][41] + isByte[i][42] + isByte[i][43] + isByte[i][44] + isByte[i][45] + isByte[i][46] + isByte[i][47] + isByte[i][48] + isByte[i][49] + isByte[i][50] + isByte[i][51] + isByte[i][52] + isByte[i][53] + isByte[i][54] + isByte[i][55] + isByte[i][56] + isByte[i][57] + isByte[i][58] + isByte[i][59] + isByte[i][60] + isByte[i][61] + isByte[i][62] + isByte[i][63]);
+        trans[i][1] <== states[i][1] * (isByte[i][12] + isByte[i][13] + isByte[i][14] + isByte[i][15] + isByte[i][16] + isByte[i][17] + isByte[i][18] + isByte[i][19] + isByte[i][20] + isByte[i][21] + isByte[i][22] + isByte[i][23] + isByte[i][24] + isByte[i][25] + isByte[i][26] + isByte[i][27] + isByte[i][28] + isByte[i][29] + isByte[i][30] + isByte[i][31] + isByte[i][32] + isByte[i][33] + isByte[i][34] + isByte[i][35] + isByte[i][36] + isByte[i][37] + isByte[i][38] + isByte[i][39] + isByte[i][40] + isByte[i][41] + isByte[i][42] + isByte[i][43] + isByte[i][44] + isByte[i][45] + isByte[i][46] + isByte[i][47] + isByte[i][48] + isByte[i][49] + isByte[i][50] + isByte[i][51] + isByte[i][52] + isByte[i][53] + isByte[i][54] + isByte[i][55] + isByte[i][56] + isByte[i][57] + isByte[i][58] + isByte[i][59] + isByte[i][60] + isByte[i][61] + isByte[i][62] + isByte[i][63]);
+        trans[i][2] <== states[i][1] * (isByte[i][1] + isByte[i][2] + isByte[i][3] + isByte[i][4] + isByte[i][5] + isByte[i][6] + isByte[i][7] + isByte[i][8] + isByte[i][9] + isByte[i][10]);
+        trans[i][3] <== states[i][1] * isByte[i][11];
+        trans[i][4] <== states[i][2] * (isByte[i][1] + isByte[i][2] + isByte[i][3] + isByte[i][4] + isByte[i][5] + isByte[i][6] + isByte[i][7] + isByte[i][8] + isByte[i][9] + isByte[i][10]);
+        trans[i][5] <== states[i][2] * isByte[i][11];
+        trans[i][6] <== states[i][3] * isByte[i][44];
+        trans[i][7] <== states[i][4] * isByte[i][50];
+        trans[i][8] <== states[i][5] * isByte[i][38];
+        trans[i][9] <== states[i][6] * isByte[i][46];
+        trans[i][10] <== states[i][7] * isByte[i][49];
+        trans[i][11] <== states[i][8] * isByte[i][0];
+        trans[i][12] <== states[i][9] * isByte[i][40];
+        trans[i][13] <== states[i][10] * isByte[i][52];
+        trans[i][14] <== states[i][11] * isByte[i][50];
+        stay[i][0] <== isPad[i] * states[i][0];
+        states[i + 1][0] <== stay[i][0];
+        stay[i][1] <== isPad[i] * states[i][1];
+        states[i + 1][1] <== trans[i][0] + trans[i][1] + stay[i][1];
+        stay[i][2] <== isPad[i] * states[i][2];
+        states[i + 1][2] <== trans[i][2] + trans[i][4] + stay[i][2];
+        stay[i][3] <== isPad[i] * states[i][3];
+        states[i + 1][3] <== trans[i][3] + trans[i][5] + stay[i][3];
+        stay[i][4] <== isPad[i] * states[i][4];
+        states[i + 1][4] <== trans[i][6] + stay[i][4];
+        stay[i][5] <== isPad[i] * states[i][5];
+        states[i + 1][5] <== trans[i][7] + stay[i][5];
+        stay[i][6] <== isPad[i] * states[i][6];
+        states[i + 1][6] <== trans[i][8] + stay[i][6];
+        stay[i][7] <== isPad[i] * states[i][7];
+        states[i + 1][7] <== trans[i][9] + stay[i][7];
+        stay[i][8] <== isPad[i] * states[i][8];
+        states[i + 1][8] <== trans[i][10] + stay[i][8];
+        stay[i][9] <== isPad[i] * states[i][9];
+        states[i + 1][9] <== trans[i][11] + stay[i][9];
+        stay[i][10] <== isPad[i] * states[i][10];
+        states[i + 1][10] <== trans[i][12] + stay[i][10];
+        stay[i][11] <== isPad[i] * states[i][11];
+        states[i + 1][11] <== trans[i][13] + stay[i][11];
+        stay[i][12] <== isPad[i] * states[i][12];
+        states[i + 1][12] <== trans[i][14] + stay[i][12];
+        // A byte without a transition leaves no state active
+        states[i + 1][0] + states[i + 1][1] + states[i + 1][2] + states[i + 1][3] + states[i + 1][4] + states[i + 1][5] + states[i + 1][6] + states[i + 1][7] + states[i + 1][8] + states[i + 1][9] + states[i + 1][10] + states[i + 1][11] + states[i + 1][12] === 1;
+    }
+
+    // Padding may only be followed by more padding
+    for (var i = 1; i < maxLen; i++) {
+        isPad[i - 1] * (1 - isPad[i]) === 0;
+    }
+
+    // The run must end in an accept state
+    states[maxLen][12] === 1;
+}
@@ -0,0 +1,637 @@
+use ark_ff::PrimeField;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use ark_r1cs_std::fields::FieldVar;
+use ark_r1cs_std::alloc::AllocVar;
+use ark_r1cs_std::boolean::Boolean;
+use ark_r1cs_std::fields::fp::FpVar;
+use ark_r1cs_std::eq::EqGadget;
+/// Regex: [^a]b
+#[derive(Clone)]
+pub struct RegexCircuit<F: PrimeField> {
+    pub input: Vec<F>,
+    pub max_len: usize,
+}
+impl<F: PrimeField> ConstraintSynthesizer<F> for RegexCircuit<F> {
+    fn generate_constraints(
+        self,
+        cs: ConstraintSystemRef<F>,
+    ) -> Result<(), SynthesisError> {
+        if self.input.len() > self.max_len {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+        let mut padded_input = self.input.clone();
+        padded_input.resize(self.max_len, F::from(0u64));
+        let input_vars = padded_input
+            .into_iter()
+            .map(|v| FpVar::new_input(cs.clone(), || Ok(v)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut valid = Boolean::constant(true);
+        let mut padding_started = Boolean::constant(false);
+        let mut current_state = FpVar::constant(F::from(0u64));
+        for current_input in input_vars.iter() {
+            let is_padded = current_input.is_eq(&FpVar::constant(F::from(0u64)))?;
+            let cond_padded = is_padded.not();
+            let mut next_state = current_state.clone();
+            let mut has_transitioned = Boolean::constant(false);
+            let is_state_0 = current_state.is_eq(&FpVar::constant(F::from(0u64)))?;
+            let cond_1 = is_state_0
+                .and(
+                    &(current_input
+                        .is_eq(&FpVar::constant(F::from(1u64)))?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(2u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(3u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(4u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(5u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(6u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(7u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(8u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(9u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(10u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(11u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(12u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(13u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(14u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(15u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(16u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(17u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(18u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(19u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(20u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(21u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(22u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(23u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(24u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(25u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(26u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(27u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(28u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(29u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(30u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(31u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(32u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(33u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(34u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(35u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(36u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(37u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(38u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(39u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(40u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(41u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(42u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(43u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(44u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(45u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(46u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(47u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(48u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(49u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(50u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(51u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(52u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(53u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(54u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(55u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(56u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(57u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(58u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(59u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(60u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(61u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(62u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(63u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(64u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(65u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(66u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(67u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(68u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(69u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(70u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(71u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(72u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(73u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(74u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(75u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(76u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(77u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(78u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(79u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(80u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(81u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(82u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(83u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(84u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(85u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(86u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(87u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(88u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(89u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(90u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(91u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(92u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(93u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(94u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(95u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(96u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(98u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(99u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(100u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(101u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(102u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(103u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(104u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(105u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(106u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(107u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(108u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(109u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(110u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(111u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(112u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(113u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(114u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(115u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(116u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(117u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(118u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(119u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(120u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(121u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(122u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(123u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(124u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(125u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(126u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(127u64)))?)?),
+                )?;
+            next_state = cond_1.select(&FpVar::constant(F::from(1u64)), &next_state)?;
+            has_transitioned = has_transitioned.or(&cond_1)?;
+            let cond_2 = is_state_0
+                .and(
+                    &(current_input
+                        .is_eq(&FpVar::constant(F::from(194u64)))?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(195u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(196u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(197u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(198u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(199u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(200u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(201u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(202u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(203u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(204u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(205u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(206u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(207u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(208u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(209u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(210u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(211u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(212u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(213u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(214u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(215u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(216u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(217u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(218u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(219u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(220u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(221u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(222u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(223u64)))?)?),
+                )?;
+            next_state = cond_2.select(&FpVar::constant(F::from(2u64)), &next_state)?;
+            has_transitioned = has_transitioned.or(&cond_2)?;
+            let cond_3 = is_state_0
+                .and(&(current_input.is_eq(&FpVar::constant(F::from(224u64)))?))?;
+            next_state = cond_3.select(&FpVar::constant(F::from(3u64)), &next_state)?;
+            has_transitioned = has_transitioned.or(&cond_3)?;
+            let cond_4 = is_state_0
+                .and(
+                    &(current_input
+                        .is_eq(&FpVar::constant(F::from(225u64)))?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(226u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(227u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(228u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(229u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(230u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(231u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(232u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(233u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(234u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(235u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(236u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(238u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(239u64)))?)?),
+                )?;
+            next_state = cond_4.select(&FpVar::constant(F::from(4u64)), &next_state)?;
+            has_transitioned = has_transitioned.or(&cond_4)?;
+            let cond_5 = is_state_0
+                .and(&(current_input.is_eq(&FpVar::constant(F::from(237u64)))?))?;
+            next_state = cond_5.select(&FpVar::constant(F::from(5u64)), &next_state)?;
+            has_transitioned = has_transitioned.or(&cond_5)?;
+            let cond_6 = is_state_0
+                .and(&(current_input.is_eq(&FpVar::constant(F::from(240u64)))?))?;
+            next_state = cond_6.select(&FpVar::constant(F::from(6u64)), &next_state)?;
+            has_transitioned = has_transitioned.or(&cond_6)?;
+            let cond_7 = is_state_0
+                .and(
+                    &(current_input
+                        .is_eq(&FpVar::constant(F::from(241u64)))?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(242u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(243u64)))?)?),
+                )?;
+            next_state = cond_7.select(&FpVar::constant(F::from(7u64)), &next_state)?;
+            has_transitioned = has_transitioned.or(&cond_7)?;
+            let cond_8 = is_state_0
+                .and(&(current_input.is_eq(&FpVar::constant(F::from(244u64)))?))?;
+            next_state = cond_8.select(&FpVar::constant(F::from(8u64)), &next_state)?;
+            has_transitioned = has_transitioned.or(&cond_8)?;
+            let is_state_1 = current_state.is_eq(&FpVar::constant(F::from(1u64)))?;
+            let cond_9 = is_state_1
+                .and(&(current_input.is_eq(&FpVar::constant(F::from(98u64)))?))?;
+            next_state = cond_9.select(&FpVar::constant(F::from(9u64)), &next_state)?;
+            has_transitioned = has_transitioned.or(&cond_9)?;
+            let is_state_2 = current_state.is_eq(&FpVar::constant(F::from(2u64)))?;
+            let cond_10 = is_state_2
+                .and(
+                    &(current_input
+                        .is_eq(&FpVar::constant(F::from(128u64)))?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(129u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(130u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(131u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(132u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(133u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(134u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(135u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(136u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(137u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(138u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(139u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(140u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(141u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(142u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(143u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(144u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(145u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(146u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(147u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(148u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(149u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(150u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(151u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(152u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(153u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(154u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(155u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(156u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(157u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(158u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(159u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(160u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(161u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(162u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(163u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(164u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(165u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(166u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(167u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(168u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(169u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(170u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(171u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(172u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(173u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(174u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(175u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(176u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(177u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(178u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(179u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(180u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(181u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(182u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(183u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(184u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(185u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(186u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(187u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(188u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(189u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(190u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(191u64)))?)?),
+                )?;
+            next_state = cond_10.select(&FpVar::constant(F::from(1u64)), &next_state)?;
+            has_transitioned = has_transitioned.or(&cond_10)?;
+            let is_state_3 = current_state.is_eq(&FpVar::constant(F::from(3u64)))?;
+            let cond_11 = is_state_3
+                .and(
+                    &(current_input
+                        .is_eq(&FpVar::constant(F::from(160u64)))?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(161u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(162u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(163u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(164u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(165u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(166u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(167u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(168u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(169u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(170u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(171u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(172u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(173u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(174u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(175u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(176u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(177u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(178u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(179u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(180u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(181u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(182u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(183u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(184u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(185u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(186u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(187u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(188u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(189u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(190u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(191u64)))?)?),
+                )?;
+            next_state = cond_11.select(&FpVar::constant(F::from(2u64)), &next_state)?;
+            has_transitioned = has_transitioned.or(&cond_11)?;
+            let is_state_4 = current_state.is_eq(&FpVar::constant(F::from(4u64)))?;
+            let cond_12 = is_state_4
+                .and(
+                    &(current_input
+                        .is_eq(&FpVar::constant(F::from(128u64)))?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(129u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(130u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(131u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(132u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(133u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(134u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(135u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(136u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(137u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(138u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(139u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(140u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(141u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(142u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(143u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(144u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(145u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(146u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(147u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(148u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(149u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(150u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(151u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(152u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(153u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(154u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(155u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(156u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(157u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(158u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(159u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(160u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(161u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(162u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(163u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(164u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(165u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(166u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(167u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(168u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(169u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(170u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(171u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(172u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(173u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(174u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(175u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(176u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(177u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(178u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(179u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(180u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(181u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(182u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(183u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(184u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(185u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(186u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(187u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(188u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(189u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(190u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(191u64)))?)?),
+                )?;
+            next_state = cond_12.select(&FpVar::constant(F::from(2u64)), &next_state)?;
+            has_transitioned = has_transitioned.or(&cond_12)?;
+            let is_state_5 = current_state.is_eq(&FpVar::constant(F::from(5u64)))?;
+            let cond_13 = is_state_5
+                .and(
+                    &(current_input
+                        .is_eq(&FpVar::constant(F::from(128u64)))?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(129u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(130u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(131u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(132u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(133u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(134u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(135u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(136u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(137u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(138u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(139u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(140u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(141u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(142u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(143u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(144u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(145u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(146u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(147u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(148u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(149u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(150u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(151u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(152u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(153u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(154u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(155u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(156u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(157u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(158u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(159u64)))?)?),
+                )?;
+            next_state = cond_13.select(&FpVar::constant(F::from(2u64)), &next_state)?;
+            has_transitioned = has_transitioned.or(&cond_13)?;
+            let is_state_6 = current_state.is_eq(&FpVar::constant(F::from(6u64)))?;
+            let cond_14 = is_state_6
+                .and(
+                    &(current_input
+                        .is_eq(&FpVar::constant(F::from(144u64)))?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(145u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(146u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(147u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(148u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(149u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(150u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(151u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(152u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(153u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(154u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(155u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(156u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(157u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(158u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(159u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(160u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(161u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(162u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(163u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(164u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(165u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(166u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(167u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(168u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(169u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(170u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(171u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(172u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(173u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(174u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(175u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(176u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(177u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(178u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(179u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(180u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(181u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(182u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(183u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(184u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(185u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(186u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(187u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(188u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(189u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(190u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(191u64)))?)?),
+                )?;
+            next_state = cond_14.select(&FpVar::constant(F::from(4u64)), &next_state)?;
+            has_transitioned = has_transitioned.or(&cond_14)?;
+            let is_state_7 = current_state.is_eq(&FpVar::constant(F::from(7u64)))?;
+            let cond_15 = is_state_7
+                .and(
+                    &(current_input
+                        .is_eq(&FpVar::constant(F::from(128u64)))?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(129u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(130u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(131u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(132u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(133u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(134u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(135u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(136u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(137u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(138u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(139u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(140u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(141u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(142u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(143u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(144u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(145u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(146u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(147u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(148u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(149u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(150u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(151u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(152u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(153u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(154u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(155u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(156u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(157u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(158u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(159u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(160u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(161u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(162u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(163u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(164u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(165u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(166u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(167u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(168u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(169u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(170u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(171u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(172u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(173u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(174u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(175u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(176u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(177u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(178u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(179u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(180u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(181u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(182u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(183u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(184u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(185u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(186u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(187u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(188u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(189u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(190u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(191u64)))?)?),
+                )?;
+            next_state = cond_15.select(&FpVar::constant(F::from(4u64)), &next_state)?;
+            has_transitioned = has_transitioned.or(&cond_15)?;
+            let is_state_8 = current_state.is_eq(&FpVar::constant(F::from(8u64)))?;
+            let cond_16 = is_state_8
+                .and(
+                    &(current_input
+                        .is_eq(&FpVar::constant(F::from(128u64)))?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(129u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(130u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(131u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(132u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(133u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(134u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(135u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(136u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(137u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(138u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(139u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(140u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(141u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(142u64)))?)?
+                        .or(&current_input.is_eq(&FpVar::constant(F::from(143u64)))?)?),
+                )?;
+            next_state = cond_16.select(&FpVar::constant(F::from(4u64)), &next_state)?;
+            has_transitioned = has_transitioned.or(&cond_16)?;
+            let invalid_transition = cond_padded.and(&has_transitioned.not())?;
+            valid = valid.and(&invalid_transition.not())?;
+            valid = valid.and(&padding_started.and(&cond_padded)?.not())?;
+            padding_started = padding_started.or(&is_padded)?;
+            current_state = next_state;
+        }
+        let mut is_accepting = Boolean::constant(false);
+        is_accepting = is_accepting
+            .or(&current_state.is_eq(&FpVar::constant(F::from(9u64)))?)?;
+        valid = valid.and(&is_accepting)?;
+        valid.enforce_equal(&Boolean::constant(true))?;
+        Ok(())
+    }
+}
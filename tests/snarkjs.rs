@@ -0,0 +1,108 @@
+mod common;
+
+use ark_bn254::{Bn254, Fr};
+use ark_ff::PrimeField;
+use ark_groth16::Groth16;
+use ark_snark::SNARK;
+use ark_std::rand::{rngs::StdRng, SeedableRng};
+use arkworks_regex_circuit::{
+    circuit::DFACircuit,
+    prover::public_inputs,
+    snarkjs::{
+        read_proof, read_public_inputs, read_verifying_key, verifying_key_to_snarkjs,
+        write_proof, write_public_inputs, write_verifying_key,
+    },
+};
+use common::dfa;
+use num_bigint::BigUint;
+use std::path::PathBuf;
+
+const MAX_LEN: usize = 16;
+
+/// The circuit of `a[bc]+d` for `input`.
+fn regex_circuit(input: &str) -> DFACircuit<Fr> {
+    DFACircuit::from_bytes(dfa("a[bc]+d"), input.as_bytes(), MAX_LEN)
+}
+
+fn output_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("arkworks_regex_{}_{}", name, std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn snarkjs_round_trip_verifies() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let circuit = regex_circuit("abcbd");
+
+    let (pk, vk) = Groth16::<Bn254>::circuit_specific_setup(circuit.clone(), &mut rng).unwrap();
+    let proof = Groth16::<Bn254>::prove(&pk, circuit.clone(), &mut rng).unwrap();
+
+    let dir = output_dir("snarkjs_round_trip");
+    write_verifying_key(&vk, dir.join("verification_key.json")).unwrap();
+    write_proof(&proof, dir.join("proof.json")).unwrap();
+    write_public_inputs(&public_inputs::<Fr>("abcbd", MAX_LEN), dir.join("public.json")).unwrap();
+
+    let vk = read_verifying_key(dir.join("verification_key.json")).unwrap();
+    let proof = read_proof(dir.join("proof.json")).unwrap();
+    let public = read_public_inputs(dir.join("public.json")).unwrap();
+
+    assert_eq!(public, public_inputs::<Fr>("abcbd", MAX_LEN));
+    assert!(Groth16::<Bn254>::verify(&vk, &public, &proof).unwrap());
+    let other = public_inputs::<Fr>("acccd", MAX_LEN);
+    assert!(!Groth16::<Bn254>::verify(&vk, &other, &proof).unwrap());
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn snarkjs_layout_matches_groth16_bn128() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let (_, vk) =
+        Groth16::<Bn254>::circuit_specific_setup(regex_circuit("abd"), &mut rng).unwrap();
+
+    let json = serde_json::to_value(verifying_key_to_snarkjs(&vk)).unwrap();
+    assert_eq!(json["protocol"], "groth16");
+    assert_eq!(json["curve"], "bn128");
+    // One public input per input byte
+    assert_eq!(json["nPublic"], MAX_LEN);
+    assert_eq!(json["IC"].as_array().unwrap().len(), MAX_LEN + 1);
+    assert_eq!(json["vk_alpha_1"][2], "1");
+    assert_eq!(json["vk_beta_2"][2], serde_json::json!(["1", "0"]));
+    assert_eq!(json["vk_alphabeta_12"].as_array().unwrap().len(), 2);
+    assert_eq!(json["vk_alphabeta_12"][0].as_array().unwrap().len(), 3);
+}
+
+#[test]
+fn snarkjs_rejects_foreign_curve() {
+    let dir = output_dir("snarkjs_foreign_curve");
+    let path = dir.join("proof.json");
+    std::fs::write(
+        &path,
+        r#"{"pi_a":["1","2","1"],"pi_b":[["0","0"],["1","0"],["0","0"]],"pi_c":["1","2","1"],"protocol":"groth16","curve":"bls12381"}"#,
+    )
+    .unwrap();
+
+    assert!(read_proof(&path).is_err());
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn snarkjs_rejects_non_canonical_field_elements() {
+    let dir = output_dir("snarkjs_non_canonical");
+    let path = dir.join("public.json");
+    let modulus = Fr::MODULUS.to_string();
+    for value in ["97", "0"] {
+        std::fs::write(&path, format!(r#"["{}"]"#, value)).unwrap();
+        assert!(read_public_inputs(&path).is_ok(), "{}", value);
+    }
+    // 97 + r reduces to 97, but is not the encoding snarkjs writes
+    let reducible = (BigUint::from(Fr::MODULUS) + 97u32).to_string();
+    for value in [modulus.as_str(), reducible.as_str(), "-1", "+97", " 97", "0x61", ""] {
+        std::fs::write(&path, format!(r#"["{}"]"#, value)).unwrap();
+        assert!(read_public_inputs(&path).is_err(), "{}", value);
+    }
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,431 @@
+mod common;
+
+use ark_bn254::Fr;
+use ark_ff::{One, PrimeField, Zero};
+use ark_r1cs_std::{alloc::AllocVar, eq::EqGadget, fields::fp::FpVar};
+use ark_relations::r1cs::{
+    ConstraintSynthesizer, ConstraintSystem, ConstraintSystemRef, SynthesisError,
+};
+use arkworks_regex_circuit::{
+    chunked::{chunk_commitment, ChunkCircuit},
+    circuit::DFACircuit,
+    prover::encode_input,
+    step::{DFAStepCircuit, StepCircuit},
+    structs::DFAGraph,
+    universal::{dfa_to_table, TableMode, UniversalRegexCircuit},
+};
+use common::{dfa, salt};
+
+#[path = "snapshots/a_bc_d.rs"]
+mod a_bc_d;
+#[path = "snapshots/alternation_star.rs"]
+mod alternation_star;
+#[path = "snapshots/gmail.rs"]
+mod gmail;
+#[path = "snapshots/gmail_packed.rs"]
+mod gmail_packed;
+#[path = "snapshots/negated_class.rs"]
+mod negated_class;
+
+const MAX_LEN: usize = 16;
+const MAX_STATES: usize = 16;
+const STEP_LEN: usize = 4;
+const FLIP_SAMPLES: usize = 256;
+
+/// Regexes with two different matching strings each, covering loops, alternations and
+/// negated classes.
+const CASES: &[(&str, &str, &str)] = &[
+    (r"a[bc]+d", "abcd", "acbd"),
+    (r"[a-z]+[0-9]*@x\.com", "bob7@x.com", "al@x.com"),
+    (r"(ab|cd)*e", "abcde", "cde"),
+    (r"[^a]b", "zb", "bb"),
+    (r"x?y{2,3}", "xyyy", "yy"),
+];
+
+const GMAIL: (&str, &str, &str) = (
+    r"^[a-zA-Z]+[0-9]*@gmail\.com$",
+    "bob7@gmail.com",
+    "alice@gmail.com",
+);
+
+/// Synthesizes a circuit for input elements; `Err` means it refused them outright.
+type Synthesize = Box<dyn Fn(Vec<Fr>) -> Result<ConstraintSystemRef<Fr>, SynthesisError>>;
+
+/// One circuit under attack, with the regex it checks and two inputs it accepts.
+struct Target {
+    name: String,
+    graph: DFAGraph,
+    input: &'static str,
+    other_input: &'static str,
+    synthesize: Synthesize,
+    /// Whether the input bytes are public elements packed into range-checked chunks.
+    packed_input: bool,
+    /// Whether the input is allocated as `u8` witnesses, so elements above 255 cannot be
+    /// expressed at all.
+    byte_witnesses: bool,
+    /// Whether the circuit has a fixed `max_len`; step circuits take any number of steps.
+    has_max_len: bool,
+}
+
+impl Target {
+    fn assert_rejected(&self, elements: Vec<Fr>, attack: &str) {
+        let attack = format!("{}: {:?} {}", self.name, self.input, attack);
+        // Refusing the elements at synthesis rejects them as well
+        if let Ok(cs) = (self.synthesize)(elements) {
+            assert_unsatisfied(&cs, &attack);
+        }
+    }
+
+    /// Synthesizes the target for `input`, which it must accept.
+    fn synthesize_accepted(&self, input: &str) -> ConstraintSystemRef<Fr> {
+        let cs = (self.synthesize)(encode_input(input)).unwrap();
+        assert!(
+            cs.is_satisfied().unwrap(),
+            "{} rejects {:?} at {:?}",
+            self.name,
+            input,
+            cs.which_is_unsatisfied().unwrap()
+        );
+        cs
+    }
+}
+
+/// Synthesizes `circuit` and inlines its linear combinations.
+///
+/// `is_satisfied` caches the value of every symbolic linear combination it evaluates, so
+/// without inlining a changed assignment would be checked against stale values.
+fn synthesize(
+    circuit: impl ConstraintSynthesizer<Fr>,
+) -> Result<ConstraintSystemRef<Fr>, SynthesisError> {
+    let cs = ConstraintSystem::<Fr>::new_ref();
+    circuit.generate_constraints(cs.clone())?;
+    cs.finalize();
+    Ok(cs)
+}
+
+fn to_bytes(elements: &[Fr]) -> Vec<u8> {
+    elements
+        .iter()
+        .map(|element| u8::try_from(element.into_bigint().as_ref()[0]).unwrap())
+        .collect()
+}
+
+fn dfa_circuit(
+    (regex, input, other_input): (&'static str, &'static str, &'static str),
+    packed_input: bool,
+) -> Target {
+    let graph = dfa(regex);
+    let circuit_graph = graph.clone();
+    Target {
+        name: format!("DFACircuit (packed: {})", packed_input),
+        graph,
+        input,
+        other_input,
+        synthesize: Box::new(move |input| {
+            synthesize(DFACircuit {
+                dfa: circuit_graph.clone(),
+                input,
+                max_len: MAX_LEN,
+                packed_input,
+            })
+        }),
+        packed_input,
+        byte_witnesses: false,
+        has_max_len: true,
+    }
+}
+
+macro_rules! generated_circuit {
+    ($module:ident, $case:expr, $packed_input:expr) => {
+        Target {
+            name: format!("generated {}", stringify!($module)),
+            graph: dfa($case.0),
+            input: $case.1,
+            other_input: $case.2,
+            synthesize: Box::new(|input| {
+                synthesize($module::RegexCircuit { input, max_len: MAX_LEN })
+            }),
+            packed_input: $packed_input,
+            byte_witnesses: false,
+            has_max_len: true,
+        }
+    };
+}
+
+fn universal_circuit(
+    (regex, input, other_input): (&'static str, &'static str, &'static str),
+    private_input: bool,
+) -> Target {
+    let graph = dfa(regex);
+    let table = dfa_to_table(&graph, MAX_STATES).unwrap();
+    Target {
+        name: format!("UniversalRegexCircuit (private input: {})", private_input),
+        graph,
+        input,
+        other_input,
+        synthesize: Box::new(move |input| {
+            synthesize(UniversalRegexCircuit {
+                table: table.clone(),
+                table_mode: TableMode::Committed,
+                table_salt: salt(),
+                input,
+                input_salt: salt(),
+                max_len: MAX_LEN,
+                packed_input: false,
+                private_input,
+            })
+        }),
+        packed_input: false,
+        byte_witnesses: false,
+        has_max_len: true,
+    }
+}
+
+/// A single chunk over the whole input that claims an accepting end state: its own if the
+/// regex accepts it, and the end state of the accepted input otherwise.
+fn chunk_circuit(
+    (regex, input, other_input): (&'static str, &'static str, &'static str),
+) -> Target {
+    let graph = dfa(regex);
+    let circuit_graph = graph.clone();
+    let honest = ChunkCircuit::<Fr>::new(
+        graph.clone(),
+        input.as_bytes(),
+        MAX_LEN,
+        0,
+        Fr::zero(),
+        salt(),
+    )
+    .unwrap();
+    Target {
+        name: "ChunkCircuit".to_string(),
+        graph,
+        input,
+        other_input,
+        synthesize: Box::new(move |input| {
+            let chunk = to_bytes(&input);
+            let mut circuit = honest.clone();
+            let run = circuit_graph.run(&chunk);
+            if run.accepted {
+                circuit.end_state = *run.states.last().unwrap();
+            }
+            circuit.commitment = chunk_commitment(&chunk, MAX_LEN, Fr::zero(), salt());
+            circuit.ends_in_padding = chunk.len() < MAX_LEN || chunk.last() == Some(&0);
+            circuit.chunk = chunk;
+            synthesize(circuit)
+        }),
+        packed_input: false,
+        byte_witnesses: true,
+        has_max_len: true,
+    }
+}
+
+/// Folds the step circuit over the input in one constraint system and exposes the final
+/// state as the public output, which must be the end state of the accepted input.
+fn step_circuit(
+    (regex, input, other_input): (&'static str, &'static str, &'static str),
+) -> Target {
+    let graph = dfa(regex);
+    let end_state = *graph.run(input.as_bytes()).states.last().unwrap();
+    let circuit = DFAStepCircuit::new(graph.clone(), STEP_LEN).unwrap();
+    Target {
+        name: "DFAStepCircuit".to_string(),
+        graph,
+        input,
+        other_input,
+        synthesize: Box::new(move |input| {
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            let mut state: Vec<FpVar<Fr>> =
+                Vec::new_witness(cs.clone(), || Ok(circuit.initial_state()))?;
+            for block in input.chunks(STEP_LEN) {
+                let mut block = block.to_vec();
+                block.resize(STEP_LEN, Fr::zero());
+                let block_vars = Vec::new_witness(cs.clone(), || Ok(block))?;
+                state = circuit.generate_step_constraints(cs.clone(), &state, &block_vars)?;
+            }
+            FpVar::new_input(cs.clone(), || Ok(Fr::from(end_state as u64)))?
+                .enforce_equal(&state[0])?;
+            cs.finalize();
+            Ok(cs)
+        }),
+        packed_input: false,
+        byte_witnesses: false,
+        has_max_len: false,
+    }
+}
+
+/// Every circuit under attack: `DFACircuit` with public and packed input bytes, the
+/// generated snapshot circuits, and the universal, chunked and step circuits.
+fn targets() -> Vec<Target> {
+    let mut targets = Vec::new();
+    for &case in CASES.iter().chain([&GMAIL]) {
+        targets.push(dfa_circuit(case, false));
+        targets.push(dfa_circuit(case, true));
+        targets.push(chunk_circuit(case));
+        targets.push(step_circuit(case));
+    }
+    // The universal circuit has the same constraints for every regex and is by far the
+    // largest, so one regex covers it
+    targets.push(universal_circuit(CASES[0], false));
+    targets.push(universal_circuit(CASES[0], true));
+    targets.extend([
+        generated_circuit!(a_bc_d, CASES[0], false),
+        generated_circuit!(alternation_star, CASES[2], false),
+        generated_circuit!(negated_class, CASES[3], false),
+        generated_circuit!(gmail, GMAIL, false),
+        generated_circuit!(gmail_packed, GMAIL, true),
+    ]);
+    targets
+}
+
+fn assert_unsatisfied(cs: &ConstraintSystemRef<Fr>, attack: &str) {
+    assert!(!cs.is_satisfied().unwrap(), "{} was accepted", attack);
+}
+
+/// Returns the indices of the witnesses constrained to be booleans by `(1 - w) * w = 0`.
+fn boolean_witnesses(cs: &ConstraintSystemRef<Fr>) -> Vec<usize> {
+    let matrices = cs.to_matrices().unwrap();
+    let num_instances = matrices.num_instance_variables;
+    (0..matrices.num_constraints)
+        .filter_map(|row| match (&matrices.a[row][..], &matrices.b[row][..]) {
+            (&[(one, 0), (minus_one, a_var)], &[(b, var)])
+                if matrices.c[row].is_empty()
+                    && one.is_one()
+                    && minus_one == -Fr::one()
+                    && b.is_one()
+                    && var == a_var
+                    && var >= num_instances =>
+            {
+                Some(var - num_instances)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Runs `attack` against every target after checking that it accepts its input.
+fn for_every_circuit(mut attack: impl FnMut(&Target)) {
+    for target in targets() {
+        target.synthesize_accepted(target.input);
+        attack(&target);
+    }
+}
+
+#[test]
+fn zero_in_the_middle_is_not_skipped() {
+    for_every_circuit(|target| {
+        let input = target.input;
+        for i in 1..input.len() {
+            let mut bytes = input.as_bytes().to_vec();
+            bytes.insert(i, 0);
+            let elements = bytes.iter().map(|&b| Fr::from(b as u64)).collect();
+            target.assert_rejected(elements, &format!("with a zero at {}", i));
+        }
+    });
+}
+
+#[test]
+fn elements_above_255_do_not_alias_bytes() {
+    for_every_circuit(|target| {
+        if target.packed_input || target.byte_witnesses {
+            return;
+        }
+        for i in 0..target.input.len() {
+            let mut elements: Vec<Fr> = encode_input(target.input);
+            elements[i] += Fr::from(256u64);
+            target.assert_rejected(elements, &format!("with byte {} shifted by 256", i));
+        }
+    });
+}
+
+#[test]
+fn inputs_longer_than_max_len_are_not_truncated() {
+    for &(regex, input, _) in CASES {
+        let mut elements: Vec<Fr> = encode_input(input);
+        elements.resize(MAX_LEN + 1, Fr::zero());
+        let circuit = DFACircuit {
+            dfa: dfa(regex),
+            input: elements,
+            max_len: MAX_LEN,
+            packed_input: false,
+        };
+        assert!(circuit.check_input().is_err());
+    }
+
+    for_every_circuit(|target| {
+        // The first MAX_LEN bytes alone would match
+        let mut elements: Vec<Fr> = encode_input(target.input);
+        elements.resize(MAX_LEN + 3, Fr::zero());
+        elements.push(Fr::from(b'~' as u64));
+        if target.has_max_len {
+            assert!(
+                matches!((target.synthesize)(elements), Err(SynthesisError::Unsatisfiable)),
+                "{}: {:?} was truncated",
+                target.name,
+                target.input
+            );
+        } else {
+            target.assert_rejected(elements, "followed by padding and more input");
+        }
+    });
+}
+
+#[test]
+fn non_accepting_final_state_is_rejected() {
+    for_every_circuit(|target| {
+        let prefix = &target.input[..target.input.len() - 1];
+        if target.graph.run(prefix.as_bytes()).accepted {
+            return;
+        }
+        target.assert_rejected(encode_input(prefix), &format!("cut to {:?}", prefix));
+    });
+}
+
+#[test]
+fn flipping_a_boolean_witness_breaks_an_accepting_assignment() {
+    for_every_circuit(|target| {
+        let cs = target.synthesize_accepted(target.input);
+        let booleans = boolean_witnesses(&cs);
+        assert!(!booleans.is_empty(), "{} has no boolean witnesses", target.name);
+        // Checking satisfaction is linear in the circuit, so only flip a sample of booleans
+        for &i in booleans.iter().step_by(booleans.len().div_ceil(FLIP_SAMPLES)) {
+            let original = cs.borrow().unwrap().witness_assignment[i];
+            cs.borrow_mut().unwrap().witness_assignment[i] = Fr::one() - original;
+            assert_unsatisfied(
+                &cs,
+                &format!("{}: {:?} with witness {} flipped", target.name, target.input, i),
+            );
+            cs.borrow_mut().unwrap().witness_assignment[i] = original;
+        }
+    });
+}
+
+#[test]
+fn public_inputs_must_match_the_witness() {
+    for_every_circuit(|target| {
+        // The step circuit's only public input is the end state, which both inputs share
+        if !target.has_max_len {
+            return;
+        }
+        let cs = target.synthesize_accepted(target.input);
+        let other = target.synthesize_accepted(target.other_input);
+        let instances = cs.borrow().unwrap().instance_assignment.clone();
+        assert_ne!(
+            other.borrow().unwrap().instance_assignment,
+            instances,
+            "{}: {:?} and {:?} have the same public inputs",
+            target.name,
+            target.input,
+            target.other_input
+        );
+        // The honest witness of the other input against the public inputs of this one
+        other.borrow_mut().unwrap().instance_assignment = instances;
+        assert_unsatisfied(
+            &other,
+            &format!(
+                "{}: the witness of {:?} with the public inputs of {:?}",
+                target.name, target.other_input, target.input
+            ),
+        );
+    });
+}
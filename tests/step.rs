@@ -0,0 +1,83 @@
+mod common;
+
+use ark_bn254::Fr;
+use ark_r1cs_std::{alloc::AllocVar, fields::fp::FpVar, R1CSVar};
+use ark_relations::r1cs::ConstraintSystem;
+use arkworks_regex_circuit::step::{fold_native, DFAStepCircuit, StepCircuit};
+use common::dfa;
+
+/// Runs every step in one constraint system, feeding each output state into the next step.
+fn fold_in_circuit(circuit: &DFAStepCircuit, input: &[u8]) -> (Fr, bool) {
+    let cs = ConstraintSystem::<Fr>::new_ref();
+    let mut state: Vec<FpVar<Fr>> =
+        Vec::new_witness(cs.clone(), || Ok(circuit.initial_state())).unwrap();
+    for block in circuit.external_inputs::<Fr>(input) {
+        let block_vars = Vec::new_witness(cs.clone(), || Ok(block)).unwrap();
+        state = circuit
+            .generate_step_constraints(cs.clone(), &state, &block_vars)
+            .unwrap();
+    }
+    (state[0].value().unwrap(), cs.is_satisfied().unwrap())
+}
+
+#[test]
+fn folded_steps_match_the_dfa() {
+    let graph = dfa(r"^[a-z]+[0-9]*@gmail\.com$");
+    let input = b"alice42@gmail.com";
+    let end_state = graph.run(input).end_state();
+
+    for block_size in [1, 3, 8] {
+        let circuit = DFAStepCircuit::new(graph.clone(), block_size).unwrap();
+        let steps = circuit.external_inputs::<Fr>(input);
+        let state = fold_native(&circuit, &circuit.initial_state(), &steps).unwrap();
+
+        assert_eq!(state[0], Fr::from(end_state as u64));
+        assert!(circuit.is_accepting(&state));
+        assert_eq!(fold_in_circuit(&circuit, input), (state[0], true));
+    }
+}
+
+#[test]
+fn invalid_byte_fails_the_step() {
+    let circuit = DFAStepCircuit::new(dfa(r"^a[bc]+d$"), 2).unwrap();
+    let steps = circuit.external_inputs::<Fr>(b"abxd");
+
+    assert!(fold_native(&circuit, &circuit.initial_state(), &steps).is_err());
+    assert!(!fold_in_circuit(&circuit, b"abxd").1);
+}
+
+#[test]
+fn padding_cannot_be_followed_by_input_in_a_later_step() {
+    // "ab" padded to a full block, then "cd": every block is valid on its own
+    let circuit = DFAStepCircuit::new(dfa(r"^a[bc]+d$"), 4).unwrap();
+    let input = b"ab\0\0cd";
+    let steps = circuit.external_inputs::<Fr>(input);
+
+    assert!(fold_native(&circuit, &circuit.initial_state(), &steps).is_err());
+    assert!(!fold_in_circuit(&circuit, input).1);
+
+    // The padding flag `padding_started` carried between steps must be 0 or 1
+    let cs = ConstraintSystem::<Fr>::new_ref();
+    let state: Vec<FpVar<Fr>> =
+        Vec::new_witness(cs.clone(), || Ok(vec![Fr::from(0u64), Fr::from(2u64)])).unwrap();
+    let block = Vec::new_witness(cs.clone(), || Ok(steps[0].clone())).unwrap();
+    circuit.generate_step_constraints(cs.clone(), &state, &block).unwrap();
+    assert!(!cs.is_satisfied().unwrap());
+    assert!(circuit.step_native(&[Fr::from(0u64), Fr::from(2u64)], &steps[0]).is_err());
+}
+
+#[test]
+fn prefix_is_not_accepting() {
+    let circuit = DFAStepCircuit::new(dfa(r"^a[bc]+d$"), 1).unwrap();
+    let steps = circuit.external_inputs::<Fr>(b"abc");
+    let state = fold_native(&circuit, &circuit.initial_state(), &steps).unwrap();
+
+    assert!(!circuit.is_accepting(&state));
+    assert_eq!(StepCircuit::<Fr>::state_len(&circuit), 2);
+    assert_eq!(StepCircuit::<Fr>::external_inputs_len(&circuit), 1);
+}
+
+#[test]
+fn empty_blocks_are_rejected() {
+    assert!(DFAStepCircuit::new(dfa(r"^a[bc]+d$"), 0).is_err());
+}
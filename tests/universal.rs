@@ -0,0 +1,101 @@
+mod common;
+
+use ark_bn254::{Bn254, Fr};
+use ark_groth16::Groth16;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystem};
+use ark_snark::SNARK;
+use ark_std::rand::{rngs::StdRng, SeedableRng};
+use arkworks_regex_circuit::{
+    errors::CompilerError,
+    prover::{encode_input, universal_public_inputs},
+    structs::DFATable,
+    universal::{dfa_to_table, TableMode, UniversalRegexCircuit},
+};
+use common::{dfa, salt};
+
+const MAX_STATES: usize = 8;
+const MAX_LEN: usize = 6;
+
+fn circuit(table: DFATable, table_mode: TableMode, input: &str) -> UniversalRegexCircuit<Fr> {
+    UniversalRegexCircuit {
+        table,
+        table_mode,
+        table_salt: salt(),
+        input: encode_input(input),
+        input_salt: salt(),
+        max_len: MAX_LEN,
+        packed_input: false,
+        private_input: false,
+    }
+}
+
+fn is_satisfied(circuit: UniversalRegexCircuit<Fr>) -> bool {
+    let cs = ConstraintSystem::<Fr>::new_ref();
+    circuit.generate_constraints(cs.clone()).unwrap();
+    cs.is_satisfied().unwrap()
+}
+
+#[test]
+fn one_setup_verifies_different_regexes() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let empty = circuit(DFATable::empty(MAX_STATES), TableMode::Public, "");
+    let (pk, vk) = Groth16::<Bn254>::circuit_specific_setup(empty, &mut rng).unwrap();
+
+    for (regex, input) in [(r"^a[bc]+d$", "abcd"), (r"^[0-9]+x$", "42x")] {
+        let table = dfa_to_table(&dfa(regex), MAX_STATES).unwrap();
+        let proof =
+            Groth16::<Bn254>::prove(&pk, circuit(table.clone(), TableMode::Public, input), &mut rng)
+                .unwrap();
+        let public =
+            universal_public_inputs::<Fr>(&table, TableMode::Public, salt(), input, MAX_LEN, false);
+        assert!(Groth16::<Bn254>::verify(&vk, &public, &proof).unwrap());
+    }
+}
+
+#[test]
+fn non_matching_input_is_rejected() {
+    let table = dfa_to_table(&dfa(r"^a[bc]+d$"), MAX_STATES).unwrap();
+
+    assert!(is_satisfied(circuit(table.clone(), TableMode::Public, "abbd")));
+    assert!(!is_satisfied(circuit(table.clone(), TableMode::Public, "ad")));
+    assert!(!is_satisfied(circuit(table, TableMode::Public, "abdx")));
+}
+
+#[test]
+fn padding_and_oversized_elements_cannot_skip_bytes() {
+    let table = dfa_to_table(&dfa(r"^a[bc]+d$"), MAX_STATES).unwrap();
+
+    let mut zero_in_the_middle = circuit(table.clone(), TableMode::Public, "");
+    zero_in_the_middle.input = [b'a', b'b', 0, b'c', b'd'].map(|b| Fr::from(b as u64)).to_vec();
+    assert!(!is_satisfied(zero_in_the_middle));
+
+    let mut oversized = circuit(table, TableMode::Public, "xabd");
+    oversized.input[0] += Fr::from(256u64);
+    assert!(!is_satisfied(oversized));
+}
+
+#[test]
+fn committed_table_exposes_one_public_input() {
+    let table = dfa_to_table(&dfa(r"^a[bc]+d$"), MAX_STATES).unwrap();
+    let cs = ConstraintSystem::<Fr>::new_ref();
+    circuit(table.clone(), TableMode::Committed, "acd")
+        .generate_constraints(cs.clone())
+        .unwrap();
+
+    assert!(cs.is_satisfied().unwrap());
+    let public =
+        universal_public_inputs::<Fr>(&table, TableMode::Committed, salt(), "acd", MAX_LEN, false);
+    assert_eq!(cs.num_instance_variables(), MAX_LEN + 2);
+    assert_eq!(cs.borrow().unwrap().instance_assignment[1..], public[..]);
+}
+
+#[test]
+fn table_serializer_rejects_oversized_graphs() {
+    let graph = dfa(r"^a[bc]+d$");
+
+    assert!(matches!(
+        dfa_to_table(&graph, graph.states.len()),
+        Err(CompilerError::GraphError(_))
+    ));
+    assert!(matches!(dfa_to_table(&graph, 257), Err(CompilerError::GraphError(_))));
+}
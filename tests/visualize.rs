@@ -0,0 +1,41 @@
+mod common;
+
+use arkworks_regex_circuit::visualize::{byte_class, GraphFormat};
+use common::dfa;
+use std::collections::BTreeSet;
+
+#[test]
+fn byte_sets_collapse_into_ranges() {
+    let letters: BTreeSet<u8> = (b'a'..=b'z').chain(b'A'..=b'Z').collect();
+    assert_eq!(byte_class(&letters), "[A-Za-z]");
+    assert_eq!(byte_class(&BTreeSet::from([b'@'])), "@");
+    assert_eq!(byte_class(&BTreeSet::from([b'a', b'b', b'x'])), "[abx]");
+    assert_eq!(byte_class(&BTreeSet::from([b'-', b']', b'\n'])), "[\\x0a\\-\\]]");
+    assert_eq!(byte_class(&BTreeSet::from([b' '])), "[ ]");
+}
+
+#[test]
+fn dot_marks_accept_states_and_highlights() {
+    let graph = dfa("a[bc]+d");
+    let dot = graph.to_dot();
+    assert!(dot.starts_with("digraph DFA {"));
+    assert!(dot.contains("start -> 0;"));
+    assert!(dot.contains("[label=\"[bc]\"]"));
+    let accept = graph.states.iter().find(|s| s.state_type == "accept").unwrap();
+    assert!(dot.contains(&format!("  {} [shape=doublecircle];", accept.state_id)));
+    assert!(!dot.contains("color=red"));
+
+    let highlighted = BTreeSet::from([(0, 1)]);
+    let dot = graph.render(GraphFormat::Dot, &highlighted);
+    assert!(dot.contains("0 -> 1 [label=\"a\", color=red"));
+}
+
+#[test]
+fn mermaid_styles_highlighted_links() {
+    let graph = dfa("ab");
+    let mermaid = graph.render(GraphFormat::Mermaid, &BTreeSet::from([(1, 2)]));
+    assert!(mermaid.starts_with("flowchart LR"));
+    assert!(mermaid.contains("s0 -->|\"a\"| s1"));
+    assert!(mermaid.contains("s2(((2)))"));
+    assert!(mermaid.contains("linkStyle 2 stroke:red"));
+}
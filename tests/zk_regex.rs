@@ -0,0 +1,142 @@
+use arkworks_regex_circuit::{
+    errors::CompilerError,
+    regex::create_regex_and_dfa_from_str_and_defs,
+    structs::SubstringDefinitionsJson,
+    zk_regex::import_zk_regex_json,
+};
+use std::collections::BTreeSet;
+
+/// `^[a-z]+@gmail\.com$` in the serialized `RegexAndDFA` layout of the zk-regex compiler,
+/// with the local part as its substring.
+const GMAIL_FIXTURE: &str = include_str!("fixtures/zk_regex_gmail.json");
+
+#[test]
+fn imports_byte_lists_keyed_by_target_state() {
+    let json = r#"{
+        "regex_pattern": "a[bc]+d",
+        "dfa": {
+            "states": [
+                { "type": "", "state": 0, "edges": { "1": [97] } },
+                { "type": "", "state": 1, "edges": { "2": [98, 99] } },
+                { "type": "", "state": 2, "edges": { "2": [98, 99], "3": [100] } },
+                { "type": "accept", "state": 3, "edges": {} }
+            ]
+        },
+        "has_end_anchor": false,
+        "substrings": {
+            "substring_ranges": [[[1, 2], [2, 2]]],
+            "substring_boundaries": null
+        }
+    }"#;
+
+    let imported = import_zk_regex_json(json).unwrap();
+    let compiled = create_regex_and_dfa_from_str_and_defs(
+        "a[bc]+d",
+        SubstringDefinitionsJson { transitions: vec![] },
+    )
+    .unwrap();
+    assert_eq!(imported.regex_pattern, "a[bc]+d");
+    assert!(imported.dfa.equivalent(&compiled.dfa).is_ok());
+    assert_eq!(
+        imported.substrings.substring_ranges,
+        vec![BTreeSet::from([(1, 2), (2, 2)])]
+    );
+}
+
+#[test]
+fn imports_string_keyed_byte_ranges() {
+    // States without ids take their position; substrings are bare transition lists
+    let json = r#"{
+        "regex": "[a-z]+@x\\.com$",
+        "graph": [
+            { "type": "", "edges": { "a-z": 1 } },
+            { "type": "", "edges": { "a-z": 1, "@": 2 } },
+            { "type": "", "edges": { "x": 3 } },
+            { "type": "", "edges": { ".": 4 } },
+            { "type": "", "edges": { "c": 5 } },
+            { "type": "", "edges": { "o": 6 } },
+            { "type": "", "edges": { "m": 7 } },
+            { "type": "accept", "edges": {} }
+        ],
+        "substrings": [[[0, 1], [1, 1]]]
+    }"#;
+
+    let imported = import_zk_regex_json(json).unwrap();
+    assert!(imported.has_end_anchor);
+    assert!(imported.dfa.run(b"alice@x.com").accepted);
+    assert!(!imported.dfa.run(b"Alice@x.com").accepted);
+    assert_eq!(imported.dfa.states[1].transitions[&1].len(), 26);
+    assert_eq!(
+        imported.substrings.substring_ranges,
+        vec![BTreeSet::from([(0, 1), (1, 1)])]
+    );
+}
+
+#[test]
+fn rejects_inconsistent_imports() {
+    let dangling = r#"{ "states": [ { "type": "accept", "state": 0, "edges": { "5": [97] } } ] }"#;
+    assert!(matches!(
+        import_zk_regex_json(dangling),
+        Err(CompilerError::GraphError(_))
+    ));
+
+    let unknown_substring = r#"{
+        "graph": { "states": [ { "type": "", "state": 0, "edges": { "a": 0 } } ] },
+        "substrings": [[[0, 1]]]
+    }"#;
+    assert!(matches!(
+        import_zk_regex_json(unknown_substring),
+        Err(CompilerError::GraphError(_))
+    ));
+}
+
+#[test]
+fn imports_the_zk_regex_compiler_layout() {
+    let imported = import_zk_regex_json(GMAIL_FIXTURE).unwrap();
+    let compiled = create_regex_and_dfa_from_str_and_defs(
+        r"^[a-z]+@gmail\.com$",
+        SubstringDefinitionsJson { transitions: vec![] },
+    )
+    .unwrap();
+
+    assert_eq!(imported.regex_pattern, compiled.regex_pattern);
+    assert!(imported.has_end_anchor);
+    assert!(imported.dfa.equivalent(&compiled.dfa).is_ok());
+    assert_eq!(
+        imported.substrings.substring_ranges,
+        vec![BTreeSet::from([(0, 1), (1, 1)])]
+    );
+    assert!(imported.substrings.substring_boundaries.is_none());
+}
+
+#[test]
+fn errors_name_the_field_that_does_not_fit() {
+    let cases = [
+        (
+            r#"{ "dfa": { "states": [ { "type": "", "edges": { "1": "a" } } ] } }"#,
+            r#"dfa.states[0].edges["1"]"#,
+        ),
+        (
+            r#"{ "dfa": { "states": [ { "type": "", "edges": { "1": [97, 300] } } ] } }"#,
+            r#"dfa.states[0].edges["1"]"#,
+        ),
+        (
+            r#"{ "graph": [ { "type": "accept" }, { "state": "one" } ] }"#,
+            "graph[1].state",
+        ),
+        (r#"{ "dfa": { "nodes": [] } }"#, "dfa"),
+        (
+            r#"{ "dfa": [ { "type": "accept" } ], "substrings": [[[0, "x"]]] }"#,
+            "substrings",
+        ),
+        (r#"[ { "state_type": 1 } ]"#, "graph[0].state_type"),
+    ];
+    for (json, path) in cases {
+        match import_zk_regex_json(json) {
+            Err(CompilerError::ParseError(message)) => {
+                assert!(message.contains(path), "{:?} does not name {}", message, path)
+            }
+            result => panic!("{} was not rejected: {:?}", json, result.map(|_| ())),
+        }
+    }
+}